@@ -10,4 +10,68 @@ pub enum ErrorCode {
     VestingEnded = 2, // 1772
     #[msg("Actual price exceeded price limit")]
     PriceLimitExceeded = 3, // 1773
+    #[msg("Claim amount exceeds vested amount")]
+    ClaimAmountExceeded = 4, // 1774
+    #[msg("Delegate tip exceeds maximum")]
+    DelegateTipExceeded = 5, // 1775
+    #[msg("Bond is not fully claimed")]
+    BondNotFullyClaimed = 6, // 1776
+    #[msg("Transaction expired")]
+    TransactionExpired = 7, // 1777
+    #[msg("Bond amount below minimum")]
+    BondOutBelowMinimum = 8, // 1778
+    #[msg("Soft cap not reached")]
+    SoftCapNotReached = 9, // 1779
+    #[msg("Refund not available")]
+    RefundNotAvailable = 10, // 177a
+    #[msg("Bond sale sold out")]
+    SaleSoldOut = 11, // 177b
+    #[msg("Hard cap below soft cap")]
+    InvalidHardCap = 12, // 177c
+    #[msg("Invalid proceeds splits")]
+    InvalidProceedsSplits = 13, // 177d
+    #[msg("Invalid proceeds split recipient")]
+    InvalidSplitRecipient = 14, // 177e
+    #[msg("Referral fee exceeds fee")]
+    InvalidReferralFee = 15, // 177f
+    #[msg("Referral does not belong to bond sale")]
+    InvalidReferral = 16, // 1780
+    #[msg("Invalid fee mode")]
+    InvalidFeeMode = 17, // 1781
+    #[msg("Invalid quote account")]
+    InvalidQuoteAccount = 18, // 1782
+    #[msg("Payer is not the mint authority")]
+    InvalidMintAuthority = 19, // 1783
+    #[msg("Invalid conversion rate")]
+    InvalidRate = 20, // 1784
+    #[msg("Invalid price mode")]
+    InvalidPriceMode = 21, // 1785
+    #[msg("Invalid price feed account")]
+    InvalidOracle = 22, // 1786
+    #[msg("Price feed is stale")]
+    StaleOracle = 23, // 1787
+    #[msg("Price feed confidence exceeds maximum")]
+    OracleConfidenceExceeded = 24, // 1788
+    #[msg("Bond amount exceeds maximum payout")]
+    MaxPayoutExceeded = 25, // 1789
+    #[msg("Debt exceeds maximum")]
+    MaxDebtExceeded = 26, // 178a
+    #[msg("Minimum velocity exceeds maximum")]
+    InvalidVelocityBounds = 27, // 178b
+    #[msg("Price would exceed the ceiling price")]
+    CeilPriceExceeded = 28, // 178c
+    #[msg("Invalid ceiling mode")]
+    InvalidCeilMode = 29, // 178d
+    #[msg("Price history does not belong to bond sale")]
+    InvalidPriceHistory = 30, // 178e
+    #[msg("Invalid volume tiers")]
+    InvalidVolumeTiers = 31, // 178f
+    #[msg("Invalid vesting terms")]
+    InvalidVestingTerms = 32, // 1790
+    #[msg("Vesting term is not offered")]
+    InvalidVestingTerm = 33, // 1791
+    #[msg("Buyer cannot refer themselves")]
+    SelfReferral = 34, // 1792
+    #[msg("Referral claim period has expired")]
+    ReferralClaimExpired = 35, // 1793
 }
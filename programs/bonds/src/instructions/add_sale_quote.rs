@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{self, Mint, TokenAccount};
+
+use anchor_spl::associated_token::get_associated_token_address;
+
+use crate::structs::{BondSale, Decimal, SaleQuote, State, TokenAmount, MAX_PROCEEDS_SPLITS};
+use crate::utils::is_native_destination;
+
+#[derive(Accounts)]
+pub struct AddSaleQuote<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        constraint = bond_sale.load()?.payer == payer.key()
+    )]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(init,
+        seeds = [b"salequotev1", bond_sale.key().as_ref(), token_quote.key().as_ref()],
+        payer = payer,
+        bump
+    )]
+    pub sale_quote: AccountLoader<'info, SaleQuote>,
+    #[account(
+        constraint = token_quote.key() != bond_sale.load()?.token_quote
+    )]
+    pub token_quote: Box<Account<'info, Mint>>,
+    #[account(init,
+        token::mint = token_quote,
+        token::authority = authority,
+        payer = payer
+    )]
+    pub token_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = bond_sale_proceeds_destination.key() == bond_sale.load()?.proceeds_destination
+    )]
+    pub bond_sale_proceeds_destination: AccountInfo<'info>,
+    #[account(
+        constraint = proceeds_destination.mint == token_quote.key()
+    )]
+    pub proceeds_destination: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// proceeds go to the associated token account of whoever receives the main quote token
+pub fn handler(ctx: Context<AddSaleQuote>, rate: u128) -> ProgramResult {
+    require!(rate != 0, InvalidRate);
+
+    let bond_sale_proceeds_destination = &ctx.accounts.bond_sale_proceeds_destination;
+    let proceeds_owner = match is_native_destination(bond_sale_proceeds_destination) {
+        true => bond_sale_proceeds_destination.key(),
+        false => Account::<TokenAccount>::try_from(bond_sale_proceeds_destination)?.owner,
+    };
+    require!(
+        ctx.accounts.proceeds_destination.key()
+            == get_associated_token_address(&proceeds_owner, &ctx.accounts.token_quote.key()),
+        InvalidQuoteAccount
+    );
+
+    let mut sale_quote = ctx.accounts.sale_quote.load_init()?;
+    *sale_quote = SaleQuote {
+        bond_sale: ctx.accounts.bond_sale.key(),
+        token_quote: ctx.accounts.token_quote.key(),
+        token_quote_account: ctx.accounts.token_quote_account.key(),
+        proceeds_destination: ctx.accounts.proceeds_destination.key(),
+        rate: Decimal::new(rate),
+        quote_amount: TokenAmount::new(0),
+        quote_raised: TokenAmount::new(0),
+        bump: *ctx.bumps.get("sale_quote").unwrap(),
+        splits_distributed: [TokenAmount::new(0); MAX_PROCEEDS_SPLITS],
+    };
+
+    ctx.accounts.bond_sale.load_mut()?.sale_quote_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, CEIL_MODE_REJECT};
+
+#[derive(Accounts)]
+pub struct ChangeCeilMode<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = payer.key() == bond_sale.load()?.payer
+    )]
+    pub payer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ChangeCeilMode>, ceil_mode: u8) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(ceil_mode <= CEIL_MODE_REJECT, InvalidCeilMode);
+
+    bond_sale.ceil_mode = ceil_mode;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, Decimal, State, FEE_MODE_HYBRID};
+
+#[derive(Accounts)]
+pub struct ChangeFeeMode<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = admin.key() == state.load()?.admin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+// bonds keep the bond fee they were bought with
+pub fn handler(ctx: Context<ChangeFeeMode>, fee_mode: u8, bond_fee: u128) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(fee_mode <= FEE_MODE_HYBRID, InvalidFeeMode);
+    require!(bond_fee <= Decimal::one().v, InvalidFeeMode);
+
+    bond_sale.fee_mode = fee_mode;
+    bond_sale.bond_fee = Decimal::new(bond_fee);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, Decimal, PriceFeed, PRICE_MODE_FIXED, PRICE_MODE_ORACLE};
+use crate::utils::get_current_timestamp;
+
+#[derive(Accounts)]
+pub struct ChangePriceMode<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    // ignored in fixed price mode
+    pub oracle: AccountInfo<'info>,
+    #[account(
+        constraint = payer.key() == bond_sale.load()?.payer
+    )]
+    pub payer: Signer<'info>,
+}
+
+// an oracle priced sale takes its floor from the feed right away, a fixed one keeps the last floor
+// debt pricing has its own parameters and is set with set_debt_pricing
+pub fn handler(
+    ctx: Context<ChangePriceMode>,
+    price_mode: u8,
+    discount: u128,
+    max_staleness: u64,
+    max_confidence: u128,
+    oracle_program: Pubkey,
+) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(price_mode <= PRICE_MODE_ORACLE, InvalidPriceMode);
+    require!(discount < Decimal::one().v, InvalidPriceMode);
+
+    if price_mode == PRICE_MODE_FIXED {
+        bond_sale.price_mode = PRICE_MODE_FIXED;
+        bond_sale.oracle = Pubkey::default();
+        bond_sale.oracle_discount = Decimal::new(0);
+        bond_sale.oracle_max_staleness = 0;
+        bond_sale.oracle_max_confidence = Decimal::new(0);
+        bond_sale.oracle_program = Pubkey::default();
+        return Ok(());
+    }
+
+    bond_sale.price_mode = PRICE_MODE_ORACLE;
+    bond_sale.oracle = ctx.accounts.oracle.key();
+    bond_sale.oracle_discount = Decimal::new(discount);
+    bond_sale.oracle_max_staleness = max_staleness;
+    bond_sale.oracle_max_confidence = Decimal::new(max_confidence);
+    bond_sale.oracle_program = oracle_program;
+
+    let feed = PriceFeed::load(&ctx.accounts.oracle, &oracle_program)?;
+    bond_sale.floor_price = bond_sale.get_oracle_floor_price(&feed, get_current_timestamp())?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, Decimal, State};
+
+#[derive(Accounts)]
+pub struct ChangeReferralFee<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = admin.key() == state.load()?.admin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ChangeReferralFee>, referral_fee: u128) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(referral_fee <= Decimal::one().v, InvalidReferralFee);
+
+    bond_sale.referral_fee = Decimal::new(referral_fee);
+    Ok(())
+}
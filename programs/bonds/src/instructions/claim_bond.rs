@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use anchor_spl::token::{transfer, TokenAccount, Transfer};
+use anchor_spl::token::{mint_to, transfer, MintTo, TokenAccount, Transfer};
 
-use crate::structs::BondSale;
+use crate::structs::{BondSale, TokenAmount};
 use crate::{
     get_signer,
-    interfaces::TransferBond,
+    interfaces::{MintBond, TransferBond},
     structs::{Bond, State},
     utils::{close, get_current_timestamp},
     SEED,
@@ -15,22 +15,32 @@ use crate::{
 pub struct ClaimBond<'info> {
     #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
-    pub bond_sale: AccountLoader<'info, BondSale>,
     #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        constraint = bond.load()?.bond_sale == bond_sale.key()
+    )]
     pub bond: AccountLoader<'info, Bond>,
     #[account(mut,
         constraint = token_bond_account.key() == bond_sale.load()?.token_bond_account
     )]
     pub token_bond_account: Account<'info, TokenAccount>,
     #[account(mut,
-        constraint = owner_bond_account.owner == owner.key(),
-        constraint = owner_bond_account.mint == token_bond_account.mint
+        constraint = token_bond.key() == bond_sale.load()?.token_bond
     )]
-    pub owner_bond_account: Account<'info, TokenAccount>,
+    pub token_bond: AccountInfo<'info>,
+    #[account(mut,
+        constraint = recipient_bond_account.mint == token_bond_account.mint
+    )]
+    pub recipient_bond_account: Account<'info, TokenAccount>,
     #[account(
         constraint = owner.key() == bond.load()?.owner
     )]
     pub owner: Signer<'info>,
+    #[account(mut,
+        constraint = rent_payer.key() == bond.load()?.rent_payer
+    )]
+    pub rent_payer: AccountInfo<'info>,
     #[account(
         constraint = authority.key() == state.load()?.authority
     )]
@@ -39,41 +49,74 @@ pub struct ClaimBond<'info> {
     pub token_program: AccountInfo<'info>,
 }
 
+impl<'info> MintBond<'info> for ClaimBond<'info> {
+    fn mint_bond(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.token_bond.clone(),
+                to: self.token_bond_account.to_account_info(),
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
 impl<'info> TransferBond<'info> for ClaimBond<'info> {
     fn transfer_bond(&self) -> CpiContext<'_, '_, '_, 'info, anchor_spl::token::Transfer<'info>> {
         CpiContext::new(
             self.token_program.to_account_info(),
             Transfer {
                 from: self.token_bond_account.to_account_info(),
-                to: self.owner_bond_account.to_account_info(),
+                to: self.recipient_bond_account.to_account_info(),
                 authority: self.authority.to_account_info().clone(),
             },
         )
     }
 }
 
-pub fn handler(ctx: Context<ClaimBond>) -> ProgramResult {
+pub fn handler(ctx: Context<ClaimBond>, amount: Option<u64>) -> ProgramResult {
     {
         let mut bond = ctx.accounts.bond.load_mut()?;
+        let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
         let state = ctx.accounts.state.load()?;
+        require!(bond_sale.is_soft_cap_reached(), SoftCapNotReached);
 
         let current_time = get_current_timestamp();
-        let amount_to_claim = bond.get_amount_to_claim(current_time).unwrap();
+        let claimable_amount = bond.get_amount_to_claim(current_time)?;
+        let amount_to_claim = match amount {
+            Some(amount) => {
+                require!(amount <= claimable_amount.v, ClaimAmountExceeded);
+                TokenAmount::new(amount)
+            }
+            None => claimable_amount,
+        };
+        bond.claimed_amount += amount_to_claim;
         bond.last_claim = current_time;
 
+        let bond_fee = bond.get_bond_fee_due();
+        bond.bond_fee_amount += bond_fee;
+        bond_sale.bond_fee_amount += bond_fee;
+
         let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        if bond_sale.is_mint_on_demand() {
+            mint_to(
+                ctx.accounts.mint_bond().with_signer(signer),
+                amount_to_claim.v,
+            )?;
+            bond_sale.minted_amount += amount_to_claim;
+        }
         transfer(
             ctx.accounts.transfer_bond().with_signer(signer),
-            amount_to_claim.v,
+            (amount_to_claim - bond_fee).v,
         )?;
     }
 
-    let mut bond = *ctx.accounts.bond.load_mut()?;
-    if bond.last_claim > bond.vesting_end {
-        bond = Default::default();
+    let bond = *ctx.accounts.bond.load()?;
+    if bond.is_fully_claimed() {
         close(
             ctx.accounts.bond.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.rent_payer.to_account_info(),
         )?;
     }
 
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token;
+use anchor_spl::token::{mint_to, transfer, MintTo, TokenAccount, Transfer};
+
+use crate::interfaces::TransferTip;
+use crate::structs::BondSale;
+use crate::{
+    get_signer,
+    interfaces::{MintBond, TransferBond},
+    structs::{Bond, State},
+    utils::{close, get_current_timestamp},
+    SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimBondDelegated<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        constraint = bond.load()?.bond_sale == bond_sale.key()
+    )]
+    pub bond: AccountLoader<'info, Bond>,
+    #[account(mut,
+        constraint = token_bond_account.key() == bond_sale.load()?.token_bond_account
+    )]
+    pub token_bond_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = token_bond.key() == bond_sale.load()?.token_bond
+    )]
+    pub token_bond: AccountInfo<'info>,
+    // delegates can only pay out to the canonical account of the owner
+    #[account(mut,
+        constraint = owner_bond_account.key()
+            == get_associated_token_address(&owner.key(), &token_bond_account.mint)
+    )]
+    pub owner_bond_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = delegate_bond_account.mint == token_bond_account.mint
+    )]
+    pub delegate_bond_account: Account<'info, TokenAccount>,
+    #[account(
+        constraint = owner.key() == bond.load()?.owner
+    )]
+    pub owner: AccountInfo<'info>,
+    #[account(
+        constraint = delegate.key() == bond.load()?.delegate
+    )]
+    pub delegate: Signer<'info>,
+    #[account(mut,
+        constraint = rent_payer.key() == bond.load()?.rent_payer
+    )]
+    pub rent_payer: AccountInfo<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> MintBond<'info> for ClaimBondDelegated<'info> {
+    fn mint_bond(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.token_bond.clone(),
+                to: self.token_bond_account.to_account_info(),
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> TransferBond<'info> for ClaimBondDelegated<'info> {
+    fn transfer_bond(&self) -> CpiContext<'_, '_, '_, 'info, anchor_spl::token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_bond_account.to_account_info(),
+                to: self.owner_bond_account.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> TransferTip<'info> for ClaimBondDelegated<'info> {
+    fn transfer_tip(&self) -> CpiContext<'_, '_, '_, 'info, anchor_spl::token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_bond_account.to_account_info(),
+                to: self.delegate_bond_account.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimBondDelegated>) -> ProgramResult {
+    {
+        let mut bond = ctx.accounts.bond.load_mut()?;
+        let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+        let state = ctx.accounts.state.load()?;
+        require!(bond_sale.is_soft_cap_reached(), SoftCapNotReached);
+
+        let current_time = get_current_timestamp();
+        let amount_to_claim = bond.get_amount_to_claim(current_time)?;
+        bond.claimed_amount += amount_to_claim;
+        bond.last_claim = current_time;
+
+        let bond_fee = bond.get_bond_fee_due();
+        bond.bond_fee_amount += bond_fee;
+        bond_sale.bond_fee_amount += bond_fee;
+
+        // the tip is paid from what is left after the fee
+        let amount_after_fee = amount_to_claim - bond_fee;
+        let tip = amount_after_fee.big_mul(bond.delegate_tip).to_token_floor();
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        if bond_sale.is_mint_on_demand() {
+            mint_to(
+                ctx.accounts.mint_bond().with_signer(signer),
+                amount_to_claim.v,
+            )?;
+            bond_sale.minted_amount += amount_to_claim;
+        }
+        transfer(
+            ctx.accounts.transfer_bond().with_signer(signer),
+            (amount_after_fee - tip).v,
+        )?;
+        if tip.v != 0 {
+            transfer(ctx.accounts.transfer_tip().with_signer(signer), tip.v)?;
+        }
+    }
+
+    let bond = *ctx.accounts.bond.load()?;
+    if bond.is_fully_claimed() {
+        close(
+            ctx.accounts.bond.to_account_info(),
+            ctx.accounts.rent_payer.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
 use anchor_spl::token;
 use anchor_spl::token::{transfer, TokenAccount, Transfer};
 
-use crate::structs::{State, TokenAmount};
+use crate::structs::State;
+use crate::utils::{
+    get_current_timestamp, is_native_destination, transfer_to_splits, transfer_unwrapped,
+};
 use crate::SEED;
-use crate::{get_signer, interfaces::TransferQuote, structs::BondSale};
+use crate::{
+    get_signer,
+    interfaces::{TransferQuote, TransferSplit, UnwrapAccounts, UnwrapQuote},
+    structs::BondSale,
+};
 
 #[derive(Accounts)]
 pub struct ClaimQuote<'info> {
@@ -15,14 +23,20 @@ pub struct ClaimQuote<'info> {
     )]
     pub bond_sale: AccountLoader<'info, BondSale>,
     #[account(mut,
-        constraint = bond_sale_quote_account.mint == bond_sale.load()?.token_quote
+        constraint = bond_sale_quote_account.key() == bond_sale.load()?.token_quote_account
     )]
     pub bond_sale_quote_account: Account<'info, TokenAccount>,
     #[account(mut,
-        constraint = payer_quote_account.mint == bond_sale.load()?.token_quote,
-        constraint = payer_quote_account.owner == payer.key()
+        constraint = proceeds_destination.key() == bond_sale.load()?.proceeds_destination
     )]
-    pub payer_quote_account: Account<'info, TokenAccount>,
+    pub proceeds_destination: AccountInfo<'info>,
+    #[account(
+        constraint = token_quote.key() == bond_sale.load()?.token_quote
+    )]
+    pub token_quote: AccountInfo<'info>,
+    #[account(mut, seeds = [b"unwrapv1", bond_sale.key().as_ref()], bump)]
+    pub unwrap_account: AccountInfo<'info>,
+    #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
         constraint = authority.key() == state.load()?.authority
@@ -30,6 +44,9 @@ pub struct ClaimQuote<'info> {
     pub authority: AccountInfo<'info>,
     #[account(address = token::ID)]
     pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> TransferQuote<'info> for ClaimQuote<'info> {
@@ -38,26 +55,75 @@ impl<'info> TransferQuote<'info> for ClaimQuote<'info> {
             self.token_program.to_account_info(),
             Transfer {
                 from: self.bond_sale_quote_account.to_account_info(),
-                to: self.payer_quote_account.to_account_info(),
+                to: self.proceeds_destination.to_account_info(),
                 authority: self.authority.clone(),
             },
         )
     }
 }
 
-pub fn handler(ctx: Context<ClaimQuote>) -> ProgramResult {
+impl<'info> TransferSplit<'info> for ClaimQuote<'info> {
+    fn transfer_split(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.bond_sale_quote_account.to_account_info(),
+                to,
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> UnwrapQuote<'info> for ClaimQuote<'info> {
+    fn unwrap_quote(&self) -> UnwrapAccounts<'info> {
+        UnwrapAccounts {
+            from: self.bond_sale_quote_account.to_account_info(),
+            unwrap_account: self.unwrap_account.clone(),
+            token_quote: self.token_quote.clone(),
+            destination: self.proceeds_destination.clone(),
+            payer: self.payer.to_account_info(),
+            authority: self.authority.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.to_account_info(),
+        }
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimQuote<'info>>) -> ProgramResult {
     let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
     let state = ctx.accounts.state.load()?;
 
-    let quote_amount = bond_sale.quote_amount;
+    require!(bond_sale.is_soft_cap_reached(), SoftCapNotReached);
+
+    let quote_amount = bond_sale.get_claimable_quote(get_current_timestamp());
+    let splits = { bond_sale.proceeds_splits };
+    let (split_amounts, destination_amount) = bond_sale.distribute_quote(quote_amount);
 
     let signer: &[&[&[u8]]] = get_signer!(state.nonce);
-    transfer(
-        ctx.accounts.transfer_quote().with_signer(signer),
-        quote_amount.v,
-    )?;
+    if is_native_destination(&ctx.accounts.proceeds_destination) {
+        let bond_sale_key = ctx.accounts.bond_sale.key();
+        let unwrap_bump = *ctx.bumps.get("unwrap_account").unwrap();
+        let unwrap_signer: &[&[&[u8]]] = &[&[b"unwrapv1", bond_sale_key.as_ref(), &[unwrap_bump]]];
+        transfer_unwrapped(ctx.accounts, destination_amount.v, unwrap_signer, signer)?;
+    } else {
+        transfer(
+            ctx.accounts.transfer_quote().with_signer(signer),
+            destination_amount.v,
+        )?;
+    }
 
-    bond_sale.quote_amount = TokenAmount::new(0);
+    transfer_to_splits(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        &splits,
+        &split_amounts,
+        signer,
+    )?;
 
     Ok(())
 }
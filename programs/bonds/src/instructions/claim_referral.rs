@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+use crate::{
+    get_signer,
+    interfaces::TransferQuote,
+    structs::{BondSale, Referral, State, TokenAmount},
+    utils::get_current_timestamp,
+    SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimReferral<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        seeds = [b"referralv1", bond_sale.key().as_ref(), referrer_quote_account.key().as_ref()],
+        bump = referral.load()?.bump
+    )]
+    pub referral: AccountLoader<'info, Referral>,
+    #[account(mut,
+        constraint = token_quote_account.key() == bond_sale.load()?.token_quote_account,
+    )]
+    pub token_quote_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = referrer_quote_account.owner == referrer.key(),
+    )]
+    pub referrer_quote_account: Account<'info, TokenAccount>,
+    pub referrer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority,
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> TransferQuote<'info> for ClaimReferral<'info> {
+    fn transfer_quote(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_account.to_account_info(),
+                to: self.referrer_quote_account.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimReferral>) -> ProgramResult {
+    let state = ctx.accounts.state.load()?;
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+    let mut referral = ctx.accounts.referral.load_mut()?;
+
+    require!(bond_sale.is_soft_cap_reached(), SoftCapNotReached);
+    require!(
+        !bond_sale.is_referral_claim_expired(get_current_timestamp()),
+        ReferralClaimExpired
+    );
+
+    let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+    token::transfer(
+        ctx.accounts.transfer_quote().with_signer(signer),
+        referral.amount.v,
+    )?;
+
+    bond_sale.referral_amount -= referral.amount;
+    referral.amount = TokenAmount::new(0);
+
+    Ok(())
+}
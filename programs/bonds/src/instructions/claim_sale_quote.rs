@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::{close_account, transfer, CloseAccount, TokenAccount, Transfer};
+
+use crate::{
+    get_signer,
+    interfaces::{CloseTokenAccount, TransferQuote, TransferSplit},
+    structs::{BondSale, SaleQuote, State},
+    utils::{close, get_current_timestamp, transfer_to_sale_quote_splits},
+    SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimSaleQuote<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        constraint = bond_sale.load()?.payer == payer.key()
+    )]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        seeds = [b"salequotev1", bond_sale.key().as_ref(), sale_quote.load()?.token_quote.as_ref()],
+        bump = sale_quote.load()?.bump
+    )]
+    pub sale_quote: AccountLoader<'info, SaleQuote>,
+    #[account(mut,
+        constraint = token_quote_account.key() == sale_quote.load()?.token_quote_account
+    )]
+    pub token_quote_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = proceeds_destination.key() == sale_quote.load()?.proceeds_destination
+    )]
+    pub proceeds_destination: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> TransferQuote<'info> for ClaimSaleQuote<'info> {
+    fn transfer_quote(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_account.to_account_info(),
+                to: self.proceeds_destination.to_account_info(),
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> TransferSplit<'info> for ClaimSaleQuote<'info> {
+    fn transfer_split(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_account.to_account_info(),
+                to,
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> CloseTokenAccount<'info> for ClaimSaleQuote<'info> {
+    fn close(
+        &self,
+        account: AccountInfo<'info>,
+        destination: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account,
+                destination,
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
+// proceeds of a sale quote vest and split like the main quote token
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimSaleQuote<'info>>) -> ProgramResult {
+    let nonce = ctx.accounts.state.load()?.nonce;
+    let signer: &[&[&[u8]]] = get_signer!(nonce);
+    let current_time = get_current_timestamp();
+
+    let (ended, quote_amount) = {
+        let bond_sale = ctx.accounts.bond_sale.load()?;
+        let mut sale_quote = ctx.accounts.sale_quote.load_mut()?;
+
+        let claimable = bond_sale.get_vested_quote(
+            sale_quote.quote_raised,
+            sale_quote.quote_amount,
+            current_time,
+        );
+        let splits = { bond_sale.proceeds_splits };
+        let (split_amounts, destination_amount) = sale_quote.distribute_quote(&splits, claimable);
+        if destination_amount.v != 0 {
+            transfer(
+                ctx.accounts.transfer_quote().with_signer(signer),
+                destination_amount.v,
+            )?;
+        }
+        transfer_to_sale_quote_splits(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            &splits,
+            &split_amounts,
+            &{ sale_quote.token_quote },
+            signer,
+        )?;
+
+        (
+            bond_sale.is_sold_out() || current_time >= bond_sale.end_time,
+            sale_quote.quote_amount,
+        )
+    };
+
+    // once nothing more can be raised or claimed the sale quote is closed,
+    // tokens sent to the vault directly go to the proceeds destination so the close never fails
+    if ended && quote_amount.is_zero() {
+        ctx.accounts.token_quote_account.reload()?;
+        let leftover = ctx.accounts.token_quote_account.amount;
+        if leftover != 0 {
+            transfer(ctx.accounts.transfer_quote().with_signer(signer), leftover)?;
+        }
+        close_account(
+            ctx.accounts
+                .close(
+                    ctx.accounts.token_quote_account.to_account_info(),
+                    ctx.accounts.payer.to_account_info(),
+                )
+                .with_signer(signer),
+        )?;
+        {
+            let mut sale_quote = ctx.accounts.sale_quote.load_mut()?;
+            *sale_quote = Default::default();
+        }
+        close(
+            ctx.accounts.sale_quote.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+        )?;
+        ctx.accounts.bond_sale.load_mut()?.sale_quote_count -= 1;
+    }
+
+    Ok(())
+}
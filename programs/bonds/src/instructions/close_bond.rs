@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{structs::Bond, utils::close};
+
+#[derive(Accounts)]
+pub struct CloseBond<'info> {
+    #[account(mut)]
+    pub bond: AccountLoader<'info, Bond>,
+    #[account(
+        constraint = owner.key() == bond.load()?.owner
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut,
+        constraint = rent_payer.key() == bond.load()?.rent_payer
+    )]
+    pub rent_payer: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<CloseBond>) -> ProgramResult {
+    {
+        let bond = ctx.accounts.bond.load()?;
+        require!(bond.is_fully_claimed(), BondNotFullyClaimed);
+    }
+
+    close(
+        ctx.accounts.bond.to_account_info(),
+        ctx.accounts.rent_payer.to_account_info(),
+    )
+}
@@ -1,26 +1,36 @@
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
 use anchor_lang::{prelude::*, solana_program::system_program};
 use anchor_spl::token;
 use anchor_spl::token::{TokenAccount, Transfer};
 use bond_sale::BondSale;
 
-use crate::math::calculate_new_price;
-use crate::utils::get_current_timestamp;
+use crate::math::{
+    calculate_buy_amount, calculate_capped_amount, calculate_end_price, calculate_new_price,
+    calculate_quote_amount,
+};
+use crate::utils::{check_expiration, get_current_timestamp};
 use crate::{
-    interfaces::TransferQuote,
-    structs::{bond_sale, token_amount::TokenAmount, Bond},
+    get_signer,
+    interfaces::{TransferFee, TransferQuote},
+    structs::{
+        bond_sale, token_amount::TokenAmount, Bond, Decimal, FeeVault, Referral, State,
+        STATUS_SOLD_OUT,
+    },
+    SEED,
 };
 
 #[derive(Accounts)]
 pub struct CreateBond<'info> {
+    #[account(mut, seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
     #[account(mut)]
     pub bond_sale: AccountLoader<'info, BondSale>,
-    #[account(zero)]
+    #[account(init, payer = payer)]
     pub bond: AccountLoader<'info, Bond>,
-    #[account(mut,
-        constraint = owner_quote_account.owner == owner.key(),
-        constraint = owner_quote_account.mint == token_quote_account.mint
-    )]
-    pub owner_quote_account: Box<Account<'info, TokenAccount>>, 
+    // the owner itself for native sales
+    #[account(mut)]
+    pub owner_quote_account: AccountInfo<'info>,
     #[account(mut,
         constraint = token_bond_account.key() == bond_sale.load()?.token_bond_account 
     )]
@@ -29,8 +39,28 @@ pub struct CreateBond<'info> {
         constraint = token_quote_account.key() == bond_sale.load()?.token_quote_account
     )]
     pub token_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"feevaultv1", bond_sale.load()?.token_quote.as_ref()],
+        bump = fee_vault.load()?.bump
+    )]
+    pub fee_vault: AccountLoader<'info, FeeVault>,
+    #[account(mut,
+        constraint = fee_vault_token_account.key() == fee_vault.load()?.token_account
+    )]
+    pub fee_vault_token_account: Box<Account<'info, TokenAccount>>,
     #[account(mut)]
     pub owner: Signer<'info>,
+    #[account(
+        constraint = oracle.key() == bond_sale.load()?.oracle
+    )]
+    pub oracle: AccountInfo<'info>,
+    pub recipient: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority
+    )]
+    pub authority: AccountInfo<'info>,
     #[account(address = token::ID)]
     pub token_program: AccountInfo<'info>,
     #[account(address = system_program::ID)]
@@ -51,39 +81,220 @@ impl<'info> TransferQuote<'info> for CreateBond<'info> {
     }
 }
 
-pub fn handler(ctx: Context<CreateBond>, amount: u64, price_limit: u128) -> ProgramResult {
-    let bond = &mut ctx.accounts.bond.load_init()?;
-    let bond_sale = &mut ctx.accounts.bond_sale.load_mut()?;
+impl<'info> TransferFee<'info> for CreateBond<'info> {
+    fn transfer_fee(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_account.to_account_info(),
+                to: self.fee_vault_token_account.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
 
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBond<'info>>,
+    amount: u64,
+    price_limit: u128,
+    expires_at: Option<u64>,
+    vesting_term: Option<u8>,
+) -> ProgramResult {
     let current_time = get_current_timestamp();
-    let sell_price = calculate_new_price(bond_sale, current_time, TokenAmount::new(amount));
-    require!(sell_price.v.le(&price_limit), PriceLimitExceeded);
+    check_expiration(current_time, expires_at)?;
+    {
+        let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+        bond_sale.refresh_floor_price(&ctx.accounts.oracle, current_time)?;
+        bond_sale.update_velocity(current_time);
+    }
+
+    buy(ctx, amount, price_limit, vesting_term, current_time)
+}
+
+pub fn exact_in_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBond<'info>>,
+    quote_amount: u64,
+    min_bond_out: u64,
+    expires_at: Option<u64>,
+    vesting_term: Option<u8>,
+) -> ProgramResult {
+    let current_time = get_current_timestamp();
+    check_expiration(current_time, expires_at)?;
+    {
+        let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+        bond_sale.refresh_floor_price(&ctx.accounts.oracle, current_time)?;
+        bond_sale.update_velocity(current_time);
+    }
+
+    let (amount, capped_amount) = {
+        let bond_sale = ctx.accounts.bond_sale.load()?;
+        let term = bond_sale.get_vesting_term(vesting_term)?;
+        let amount = calculate_buy_amount(
+            &bond_sale,
+            current_time,
+            TokenAmount::new(quote_amount),
+            &term,
+        );
+        (
+            amount,
+            calculate_capped_amount(&bond_sale, current_time, amount, &term),
+        )
+    };
+    require!(capped_amount.v >= min_bond_out, BondOutBelowMinimum);
+
+    // buy caps the amount again, so a purchase cut by the hard cap ends the sale
+    buy(ctx, amount.v, u128::MAX, vesting_term, current_time)
+}
+
+// native sales take lamports from the owner and wrap them in the quote vault
+fn deposit_quote(
+    accounts: &CreateBond,
+    bond_sale: &BondSale,
+    amount: TokenAmount,
+) -> ProgramResult {
+    if !bond_sale.is_native() {
+        let owner_quote_account = Account::<TokenAccount>::try_from(&accounts.owner_quote_account)?;
+        require!(
+            owner_quote_account.owner == accounts.owner.key()
+                && owner_quote_account.mint == bond_sale.token_quote,
+            InvalidQuoteAccount
+        );
+        return token::transfer(accounts.transfer_quote(), amount.get());
+    }
+
+    require!(
+        accounts.owner_quote_account.key() == accounts.owner.key(),
+        InvalidQuoteAccount
+    );
+    invoke(
+        &system_instruction::transfer(
+            accounts.owner.key,
+            &accounts.token_quote_account.key(),
+            amount.get(),
+        ),
+        &[
+            accounts.owner.to_account_info(),
+            accounts.token_quote_account.to_account_info(),
+            accounts.system_program.clone(),
+        ],
+    )?;
+    invoke(
+        &spl_token::instruction::sync_native(&token::ID, &accounts.token_quote_account.key())?,
+        &[
+            accounts.token_quote_account.to_account_info(),
+            accounts.token_program.clone(),
+        ],
+    )
+}
+
+// the referral account of the referrer can be passed as the only remaining account
+fn buy<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBond<'info>>,
+    amount: u64,
+    price_limit: u128,
+    vesting_term: Option<u8>,
+    current_time: u64,
+) -> ProgramResult {
+    let bond = &mut ctx.accounts.bond.load_init()?;
+    let bond_sale = &mut ctx.accounts.bond_sale.load_mut()?;
+    let term = bond_sale.get_vesting_term(vesting_term)?;
 
     require!(
         amount <= bond_sale.remaining_amount.v,
         InsufficientTokenAmount
     );
-    let buy_amount = amount;
-    let quote_amount = TokenAmount::new(amount).big_mul(sell_price).to_token_ceil();
-    let fee = quote_amount.big_mul(bond_sale.fee).to_token_ceil();
+    require!(
+        !bond_sale.is_soft_cap_failed(current_time),
+        SoftCapNotReached
+    );
+    require!(!bond_sale.is_sold_out(), SaleSoldOut);
+
+    // the last purchase is partially filled up to the hard cap
+    let buy_amount =
+        calculate_capped_amount(bond_sale, current_time, TokenAmount::new(amount), &term);
+    require!(buy_amount.v != 0 || amount == 0, SaleSoldOut);
+    bond_sale.check_debt_limits(buy_amount, current_time)?;
+    require!(
+        !bond_sale.is_ceil_price_rejected(calculate_end_price(bond_sale, current_time, buy_amount)),
+        CeilPriceExceeded
+    );
+
+    let sell_price = calculate_new_price(bond_sale, current_time, buy_amount);
+    let sell_price = term.apply_discount(bond_sale.apply_volume_discount(sell_price, buy_amount));
+    require!(sell_price.v.le(&price_limit), PriceLimitExceeded);
+
+    let quote_amount = calculate_quote_amount(sell_price, buy_amount);
+    let fee = quote_amount
+        .big_mul(bond_sale.get_quote_fee())
+        .to_token_ceil();
     let quote_after_fee = quote_amount - fee;
 
+    let (referral, referral_amount) = match ctx.remaining_accounts.first() {
+        Some(referral_info) => {
+            let referral_loader = AccountLoader::<Referral>::try_from(referral_info)?;
+            let mut referral = referral_loader.load_mut()?;
+            require!(
+                referral.bond_sale == ctx.accounts.bond_sale.key(),
+                InvalidReferral
+            );
+            require!(referral.referrer != ctx.accounts.owner.key(), SelfReferral);
+
+            let referral_amount = bond_sale.calculate_referral_amount(fee);
+            referral.amount += referral_amount;
+            (referral_loader.key(), referral_amount)
+        }
+        None => (Pubkey::default(), TokenAmount::new(0)),
+    };
+
     **bond = Bond {
         bond_sale: ctx.accounts.bond_sale.key(),
         token_bond: bond_sale.token_bond,
-        owner: ctx.accounts.owner.key(),
-        bond_amount: TokenAmount::new(buy_amount),
-        last_claim: get_current_timestamp(),
-        vesting_start: get_current_timestamp(),
-        vesting_end: get_current_timestamp() + bond_sale.vesting_time,
+        owner: ctx.accounts.recipient.key(),
+        bond_amount: buy_amount,
+        last_claim: current_time,
+        vesting_start: current_time,
+        vesting_end: current_time + term.vesting_time,
         id: bond_sale.next_bond,
+        claimed_amount: TokenAmount::new(0),
+        delegate: Pubkey::default(),
+        delegate_tip: Decimal::new(0),
+        rent_payer: ctx.accounts.payer.key(),
+        quote_amount,
+        fee_amount: fee,
+        referral,
+        referral_amount,
+        bond_fee: bond_sale.get_bond_fee(),
+        bond_fee_amount: TokenAmount::new(0),
+        sale_quote: Pubkey::default(),
+        vesting_time: term.vesting_time,
+        vesting_discount: term.discount,
     };
 
-    token::transfer(ctx.accounts.transfer_quote(), quote_amount.get())?;
+    deposit_quote(ctx.accounts, bond_sale, quote_amount)?;
 
     bond_sale.quote_amount += quote_after_fee;
-    bond_sale.fee_amount += fee;
+    bond_sale.quote_raised += quote_after_fee;
+    bond_sale.fee_amount += fee - referral_amount;
+    bond_sale.referral_amount += referral_amount;
     bond_sale.next_bond += 1;
 
+    // fees stay refundable in the sale until the soft cap is reached
+    if bond_sale.is_soft_cap_reached() && !bond_sale.fee_amount.is_zero() {
+        let mut state = ctx.accounts.state.load_mut()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::transfer(
+            ctx.accounts.transfer_fee().with_signer(signer),
+            bond_sale.fee_amount.v,
+        )?;
+
+        state.total_fee += bond_sale.fee_amount;
+        bond_sale.fee_amount = TokenAmount::new(0);
+    }
+
+    if buy_amount.v < amount || bond_sale.is_hard_cap_reached() {
+        bond_sale.status = STATUS_SOLD_OUT;
+    }
+
     Ok(())
 }
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token;
+use anchor_spl::token::{TokenAccount, Transfer};
+
+use crate::math::{
+    calculate_capped_amount, calculate_end_price, calculate_new_price, calculate_quote_amount,
+};
+use crate::utils::{check_expiration, get_current_timestamp};
+use crate::{
+    interfaces::{TransferFee, TransferQuote},
+    structs::{Bond, BondSale, Decimal, FeeVault, SaleQuote, State, TokenAmount, STATUS_SOLD_OUT},
+};
+
+#[derive(Accounts)]
+pub struct CreateBondWithSaleQuote<'info> {
+    #[account(mut, seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        seeds = [b"salequotev1", bond_sale.key().as_ref(), sale_quote.load()?.token_quote.as_ref()],
+        bump = sale_quote.load()?.bump
+    )]
+    pub sale_quote: AccountLoader<'info, SaleQuote>,
+    #[account(init, payer = payer)]
+    pub bond: AccountLoader<'info, Bond>,
+    #[account(mut,
+        constraint = owner_quote_account.owner == owner.key(),
+        constraint = owner_quote_account.mint == sale_quote.load()?.token_quote
+    )]
+    pub owner_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = token_quote_account.key() == sale_quote.load()?.token_quote_account
+    )]
+    pub token_quote_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"feevaultv1", sale_quote.load()?.token_quote.as_ref()],
+        bump = fee_vault.load()?.bump
+    )]
+    pub fee_vault: AccountLoader<'info, FeeVault>,
+    #[account(mut,
+        constraint = fee_vault_token_account.key() == fee_vault.load()?.token_account
+    )]
+    pub fee_vault_token_account: Box<Account<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+    #[account(
+        constraint = oracle.key() == bond_sale.load()?.oracle
+    )]
+    pub oracle: AccountInfo<'info>,
+    pub recipient: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> TransferQuote<'info> for CreateBondWithSaleQuote<'info> {
+    fn transfer_quote(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.owner_quote_account.to_account_info(),
+                to: self.token_quote_account.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> TransferFee<'info> for CreateBondWithSaleQuote<'info> {
+    fn transfer_fee(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.owner_quote_account.to_account_info(),
+                to: self.fee_vault_token_account.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+// prices are in the main quote token, only sales past the soft cap take other quote tokens
+// so refunds never involve them
+pub fn handler(
+    ctx: Context<CreateBondWithSaleQuote>,
+    amount: u64,
+    price_limit: u128,
+    expires_at: Option<u64>,
+    vesting_term: Option<u8>,
+) -> ProgramResult {
+    let current_time = get_current_timestamp();
+    check_expiration(current_time, expires_at)?;
+
+    let bond = &mut ctx.accounts.bond.load_init()?;
+    let bond_sale = &mut ctx.accounts.bond_sale.load_mut()?;
+    let sale_quote = &mut ctx.accounts.sale_quote.load_mut()?;
+
+    bond_sale.refresh_floor_price(&ctx.accounts.oracle, current_time)?;
+    bond_sale.update_velocity(current_time);
+    let term = bond_sale.get_vesting_term(vesting_term)?;
+
+    require!(
+        amount <= bond_sale.remaining_amount.v,
+        InsufficientTokenAmount
+    );
+    require!(bond_sale.is_soft_cap_reached(), SoftCapNotReached);
+    require!(!bond_sale.is_sold_out(), SaleSoldOut);
+
+    let buy_amount =
+        calculate_capped_amount(bond_sale, current_time, TokenAmount::new(amount), &term);
+    require!(buy_amount.v != 0 || amount == 0, SaleSoldOut);
+    bond_sale.check_debt_limits(buy_amount, current_time)?;
+    require!(
+        !bond_sale.is_ceil_price_rejected(calculate_end_price(bond_sale, current_time, buy_amount)),
+        CeilPriceExceeded
+    );
+
+    let sell_price = calculate_new_price(bond_sale, current_time, buy_amount);
+    let sell_price = term.apply_discount(bond_sale.apply_volume_discount(sell_price, buy_amount));
+    require!(sell_price.v.le(&price_limit), PriceLimitExceeded);
+
+    let quote_amount = sale_quote.get_quote_amount(calculate_quote_amount(sell_price, buy_amount));
+    let fee = quote_amount
+        .big_mul(bond_sale.get_quote_fee())
+        .to_token_ceil();
+    let quote_after_fee = quote_amount - fee;
+
+    **bond = Bond {
+        bond_sale: ctx.accounts.bond_sale.key(),
+        token_bond: bond_sale.token_bond,
+        owner: ctx.accounts.recipient.key(),
+        bond_amount: buy_amount,
+        last_claim: current_time,
+        vesting_start: current_time,
+        vesting_end: current_time + term.vesting_time,
+        id: bond_sale.next_bond,
+        claimed_amount: TokenAmount::new(0),
+        delegate: Pubkey::default(),
+        delegate_tip: Decimal::new(0),
+        rent_payer: ctx.accounts.payer.key(),
+        quote_amount,
+        fee_amount: fee,
+        referral: Pubkey::default(),
+        referral_amount: TokenAmount::new(0),
+        bond_fee: bond_sale.get_bond_fee(),
+        bond_fee_amount: TokenAmount::new(0),
+        sale_quote: ctx.accounts.sale_quote.key(),
+        vesting_time: term.vesting_time,
+        vesting_discount: term.discount,
+    };
+
+    token::transfer(ctx.accounts.transfer_quote(), quote_after_fee.v)?;
+    if !fee.is_zero() {
+        token::transfer(ctx.accounts.transfer_fee(), fee.v)?;
+        ctx.accounts.state.load_mut()?.total_fee += fee;
+    }
+
+    sale_quote.quote_amount += quote_after_fee;
+    sale_quote.quote_raised += quote_after_fee;
+    bond_sale.sale_quote_raised += sale_quote.get_pricing_amount(quote_after_fee);
+    bond_sale.next_bond += 1;
+
+    if buy_amount.v < amount || bond_sale.is_hard_cap_reached() {
+        bond_sale.status = STATUS_SOLD_OUT;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{self, Mint, TokenAccount};
+
+use crate::structs::{FeeVault, State};
+
+#[derive(Accounts)]
+pub struct CreateFeeVault<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(init,
+        seeds = [b"feevaultv1", token_quote.key().as_ref()],
+        payer = payer,
+        bump
+    )]
+    pub fee_vault: AccountLoader<'info, FeeVault>,
+    pub token_quote: Box<Account<'info, Mint>>,
+    #[account(init,
+        token::mint = token_quote,
+        token::authority = authority,
+        payer = payer
+    )]
+    pub fee_vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<CreateFeeVault>) -> ProgramResult {
+    let mut fee_vault = ctx.accounts.fee_vault.load_init()?;
+
+    *fee_vault = FeeVault {
+        token_quote: ctx.accounts.token_quote.key(),
+        token_account: ctx.accounts.fee_vault_token_account.key(),
+        bump: *ctx.bumps.get("fee_vault").unwrap(),
+    };
+
+    Ok(())
+}
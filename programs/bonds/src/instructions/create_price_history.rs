@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::structs::{BondSale, PriceHistory};
+
+#[derive(Accounts)]
+pub struct CreatePriceHistory<'info> {
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(init,
+        seeds = [b"pricehistoryv1", bond_sale.key().as_ref()],
+        payer = payer,
+        bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<CreatePriceHistory>) -> ProgramResult {
+    let mut price_history = ctx.accounts.price_history.load_init()?;
+
+    *price_history = PriceHistory {
+        bond_sale: ctx.accounts.bond_sale.key(),
+        bump: *ctx.bumps.get("price_history").unwrap(),
+        ..Default::default()
+    };
+
+    Ok(())
+}
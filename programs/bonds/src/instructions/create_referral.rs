@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::TokenAccount;
+
+use crate::structs::{BondSale, Referral, TokenAmount};
+
+#[derive(Accounts)]
+pub struct CreateReferral<'info> {
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(init,
+        seeds = [b"referralv1", bond_sale.key().as_ref(), referrer_quote_account.key().as_ref()],
+        payer = payer,
+        bump
+    )]
+    pub referral: AccountLoader<'info, Referral>,
+    #[account(
+        constraint = referrer_quote_account.mint == bond_sale.load()?.token_quote
+    )]
+    pub referrer_quote_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<CreateReferral>) -> ProgramResult {
+    let mut referral = ctx.accounts.referral.load_init()?;
+
+    *referral = Referral {
+        bond_sale: ctx.accounts.bond_sale.key(),
+        referrer_quote_account: ctx.accounts.referrer_quote_account.key(),
+        amount: TokenAmount::new(0),
+        bump: *ctx.bumps.get("referral").unwrap(),
+        referrer: ctx.accounts.referrer_quote_account.owner,
+    };
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
-use crate::structs::State;
+use crate::structs::{State, TokenAmount};
 
 #[derive(Accounts)]
 #[instruction(nonce: u8)]
@@ -24,6 +24,8 @@ pub fn handler(ctx: Context<CreateState>, nonce: u8) -> ProgramResult {
         next_bond_sale: 0,
         bump: *ctx.bumps.get("state").unwrap(),
         nonce,
+        total_fee: TokenAmount::new(0),
+        total_withdrawn: TokenAmount::new(0),
     };
 
     Ok(())
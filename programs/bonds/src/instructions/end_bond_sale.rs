@@ -1,12 +1,22 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
 use anchor_spl::token;
-use anchor_spl::token::{close_account, transfer, CloseAccount, TokenAccount, Transfer};
+use anchor_spl::token::{
+    close_account, mint_to, set_authority, transfer, CloseAccount, MintTo, SetAuthority,
+    TokenAccount, Transfer,
+};
+use spl_token::instruction::AuthorityType;
 
 use crate::{
     get_signer,
-    interfaces::{CloseTokenAccount, TransferBond, TransferFee, TransferQuote},
-    structs::{BondSale, State},
-    utils::close,
+    interfaces::{
+        CloseTokenAccount, MintBond, SetMintAuthority, TransferBond, TransferQuote, TransferSplit,
+        UnwrapAccounts, UnwrapQuote,
+    },
+    structs::{BondSale, State, TokenAmount, SUPPLY_MODE_TRANSFER},
+    utils::{
+        close, get_current_timestamp, is_native_destination, transfer_to_splits, transfer_unwrapped,
+    },
     SEED,
 };
 
@@ -25,20 +35,24 @@ pub struct EndBondSale<'info> {
     )]
     pub token_bond_account: Box<Account<'info, TokenAccount>>,
     #[account(mut,
-        constraint = &payer_quote_account.owner == payer.key,
-        constraint = payer_quote_account.mint == bond_sale.load()?.token_quote
+        constraint = token_bond.key() == bond_sale.load()?.token_bond
+    )]
+    pub token_bond: AccountInfo<'info>,
+    #[account(mut,
+        constraint = proceeds_destination.key() == bond_sale.load()?.proceeds_destination
     )]
-    pub payer_quote_account: Box<Account<'info, TokenAccount>>,
+    pub proceeds_destination: AccountInfo<'info>,
+    #[account(
+        constraint = token_quote.key() == bond_sale.load()?.token_quote
+    )]
+    pub token_quote: AccountInfo<'info>,
+    #[account(mut, seeds = [b"unwrapv1", bond_sale.key().as_ref()], bump)]
+    pub unwrap_account: AccountInfo<'info>,
     #[account(mut,
         constraint = &payer_bond_account.owner == payer.key,
         constraint = payer_bond_account.mint == bond_sale.load()?.token_bond
     )]
     pub payer_bond_account: Box<Account<'info, TokenAccount>>,
-    #[account(mut,
-        constraint = admin_quote_account.owner == state.load()?.admin,
-        constraint = admin_quote_account.mint == bond_sale.load()?.token_quote
-    )]
-    pub admin_quote_account: Box<Account<'info, TokenAccount>>,
     #[account(mut,
         constraint = authority.key() == state.load()?.authority
     )]
@@ -49,6 +63,9 @@ pub struct EndBondSale<'info> {
     pub payer: Signer<'info>,
     #[account(address = token::ID)]
     pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> TransferQuote<'info> for EndBondSale<'info> {
@@ -57,7 +74,23 @@ impl<'info> TransferQuote<'info> for EndBondSale<'info> {
             self.token_program.to_account_info(),
             Transfer {
                 from: self.token_quote_account.to_account_info(),
-                to: self.payer_quote_account.to_account_info(),
+                to: self.proceeds_destination.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> TransferSplit<'info> for EndBondSale<'info> {
+    fn transfer_split(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_account.to_account_info(),
+                to,
                 authority: self.authority.to_account_info().clone(),
             },
         )
@@ -77,19 +110,47 @@ impl<'info> TransferBond<'info> for EndBondSale<'info> {
     }
 }
 
-impl<'info> TransferFee<'info> for EndBondSale<'info> {
-    fn transfer_fee(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+impl<'info> MintBond<'info> for EndBondSale<'info> {
+    fn mint_bond(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
         CpiContext::new(
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.token_quote_account.to_account_info(),
-                to: self.admin_quote_account.to_account_info(),
-                authority: self.authority.to_account_info().clone(),
+            MintTo {
+                mint: self.token_bond.clone(),
+                to: self.token_bond_account.to_account_info(),
+                authority: self.authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> SetMintAuthority<'info> for EndBondSale<'info> {
+    fn set_mint_authority(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.authority.clone(),
+                account_or_mint: self.token_bond.clone(),
             },
         )
     }
 }
 
+impl<'info> UnwrapQuote<'info> for EndBondSale<'info> {
+    fn unwrap_quote(&self) -> UnwrapAccounts<'info> {
+        UnwrapAccounts {
+            from: self.token_quote_account.to_account_info(),
+            unwrap_account: self.unwrap_account.clone(),
+            token_quote: self.token_quote.clone(),
+            destination: self.proceeds_destination.clone(),
+            payer: self.payer.to_account_info(),
+            authority: self.authority.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.to_account_info(),
+        }
+    }
+}
+
 impl<'info> CloseTokenAccount<'info> for EndBondSale<'info> {
     fn close(
         &self,
@@ -107,31 +168,112 @@ impl<'info> CloseTokenAccount<'info> for EndBondSale<'info> {
     }
 }
 
-pub fn handler(ctx: Context<EndBondSale>) -> ProgramResult {
+// bonds sold but not claimed yet are minted into the vault, so claims switch to transfers
+// once the mint authority is back with the payer
+fn end_minting(accounts: &EndBondSale, amount: TokenAmount, signer: &[&[&[u8]]]) -> ProgramResult {
+    if amount.v != 0 {
+        mint_to(accounts.mint_bond().with_signer(signer), amount.v)?;
+    }
+    set_authority(
+        accounts.set_mint_authority().with_signer(signer),
+        AuthorityType::MintTokens,
+        Some(accounts.payer.key()),
+    )?;
+
+    let mut bond_sale = accounts.bond_sale.load_mut()?;
+    bond_sale.minted_amount += amount;
+    bond_sale.supply_mode = SUPPLY_MODE_TRANSFER;
+    Ok(())
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, EndBondSale<'info>>) -> ProgramResult {
     let state = ctx.accounts.state.load()?;
     let signer: &[&[&[u8]]] = get_signer!(state.nonce);
     {
         let bond_sale = *ctx.accounts.bond_sale.load()?;
+        let current_time = get_current_timestamp();
 
-        if bond_sale.quote_amount.v != 0 {
-            transfer(
-                ctx.accounts.transfer_quote().with_signer(signer),
-                bond_sale.quote_amount.v,
-            )?;
-        }
+        if bond_sale.is_soft_cap_failed(current_time) {
+            // sold bonds are refunded, so the whole supply goes back to the payer
+            let bond_balance = ctx.accounts.token_bond_account.amount;
+            if bond_balance != 0 {
+                transfer(
+                    ctx.accounts.transfer_bond().with_signer(signer),
+                    bond_balance,
+                )?;
+            }
+            ctx.accounts.bond_sale.load_mut()?.remaining_amount = TokenAmount::new(0);
+            if bond_sale.is_mint_on_demand() {
+                end_minting(ctx.accounts, TokenAmount::new(0), signer)?;
+            }
 
-        if bond_sale.remaining_amount.v != 0 {
-            transfer(
-                ctx.accounts.transfer_bond().with_signer(signer),
-                bond_sale.remaining_amount.v,
-            )?;
-        }
+            // keep the sale open until every buyer is refunded
+            if bond_sale.quote_amount.v != 0
+                || bond_sale.fee_amount.v != 0
+                || bond_sale.referral_amount.v != 0
+                || bond_sale.sale_quote_count != 0
+            {
+                return Ok(());
+            }
+        } else {
+            require!(bond_sale.is_soft_cap_reached(), SoftCapNotReached);
+
+            let claimable_quote = bond_sale.get_claimable_quote(current_time);
+            let (split_amounts, mut destination_amount) = ctx
+                .accounts
+                .bond_sale
+                .load_mut()?
+                .distribute_quote(claimable_quote);
 
-        if bond_sale.fee_amount.v != 0 {
-            transfer(
-                ctx.accounts.transfer_fee().with_signer(signer),
-                bond_sale.fee_amount.v,
+            // referral fees nobody claimed in time are swept along with the proceeds
+            let mut referral_amount = bond_sale.referral_amount;
+            if bond_sale.is_referral_claim_expired(current_time) {
+                destination_amount += referral_amount;
+                referral_amount = TokenAmount::new(0);
+                ctx.accounts.bond_sale.load_mut()?.referral_amount = referral_amount;
+            }
+            if is_native_destination(&ctx.accounts.proceeds_destination) {
+                let bond_sale_key = ctx.accounts.bond_sale.key();
+                let unwrap_bump = *ctx.bumps.get("unwrap_account").unwrap();
+                let unwrap_signer: &[&[&[u8]]] =
+                    &[&[b"unwrapv1", bond_sale_key.as_ref(), &[unwrap_bump]]];
+                transfer_unwrapped(ctx.accounts, destination_amount.v, unwrap_signer, signer)?;
+            } else if destination_amount.v != 0 {
+                transfer(
+                    ctx.accounts.transfer_quote().with_signer(signer),
+                    destination_amount.v,
+                )?;
+            }
+            transfer_to_splits(
+                ctx.accounts,
+                ctx.remaining_accounts,
+                &{ bond_sale.proceeds_splits },
+                &split_amounts,
+                signer,
             )?;
+
+            // unsold supply is never minted
+            if bond_sale.is_mint_on_demand() {
+                end_minting(ctx.accounts, bond_sale.get_unminted_amount(), signer)?;
+            } else if bond_sale.remaining_amount.v != 0 {
+                transfer(
+                    ctx.accounts.transfer_bond().with_signer(signer),
+                    bond_sale.remaining_amount.v,
+                )?;
+            }
+
+            // keep the sale open until the locked proceeds are vested, referrals are claimed or expired,
+            // bond fees are claimed
+            // and every sale quote is closed
+            if claimable_quote.v != bond_sale.quote_amount.v
+                || referral_amount.v != 0
+                || bond_sale.bond_fee_amount.v != 0
+                || bond_sale.sale_quote_count != 0
+            {
+                let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+                bond_sale.remaining_amount = TokenAmount::new(0);
+                return Ok(());
+            }
         }
     }
 
@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{self, SetAuthority, Transfer};
 use anchor_spl::token::{Mint, TokenAccount};
+use spl_token::instruction::AuthorityType;
 
-use crate::interfaces::TransferBond;
-use crate::structs::{BondSale, Decimal, State, TokenAmount};
-use crate::utils::get_current_timestamp;
+use crate::interfaces::{SetMintAuthority, TransferBond};
+use crate::structs::{
+    BondSale, Decimal, ProceedsSplit, ProceedsSplitParams, State, TokenAmount, VestingTerm,
+    VolumeTier, BPS_DENOMINATOR, CEIL_MODE_CAP, FEE_MODE_QUOTE, MAX_PROCEEDS_SPLITS,
+    MAX_VESTING_TERMS, MAX_VOLUME_TIERS, PRICE_MODE_FIXED, STATUS_ACTIVE, SUPPLY_MODE_MINT,
+    SUPPLY_MODE_TRANSFER,
+};
+use crate::utils::{get_current_timestamp, is_native_destination};
 
 #[derive(Accounts)]
 pub struct InitBondSale<'info> {
@@ -13,6 +20,7 @@ pub struct InitBondSale<'info> {
     pub state: AccountLoader<'info, State>,
     #[account(zero)]
     pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut)]
     pub token_bond: Box<Account<'info, Mint>>,
     pub token_quote: Box<Account<'info, Mint>>,
     #[account(init,
@@ -32,6 +40,7 @@ pub struct InitBondSale<'info> {
         constraint = payer_bond_account.owner == payer.key()
     )]
     pub payer_bond_account: Box<Account<'info, TokenAccount>>,
+    pub proceeds_destination: AccountInfo<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -58,6 +67,19 @@ impl<'info> TransferBond<'info> for InitBondSale<'info> {
     }
 }
 
+impl<'info> SetMintAuthority<'info> for InitBondSale<'info> {
+    fn set_mint_authority(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.payer.to_account_info(),
+                account_or_mint: self.token_bond.to_account_info(),
+            },
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<InitBondSale>,
     floor_price: u128,
@@ -66,7 +88,40 @@ pub fn handler(
     supply: u64,
     duration: u64,
     vesting_time: u64,
+    soft_cap: u64,
+    hard_cap: u64,
+    proceeds_vesting_time: u64,
+    proceeds_splits: Vec<ProceedsSplitParams>,
+    mint_on_demand: bool,
 ) -> ProgramResult {
+    require!(hard_cap == 0 || hard_cap >= soft_cap, InvalidHardCap);
+    require!(
+        proceeds_splits.len() <= MAX_PROCEEDS_SPLITS,
+        InvalidProceedsSplits
+    );
+
+    let mut splits = [ProceedsSplit::default(); MAX_PROCEEDS_SPLITS];
+    let mut total_bps: u16 = 0;
+    for (split, params) in splits.iter_mut().zip(proceeds_splits.iter()) {
+        require!(params.bps != 0, InvalidProceedsSplits);
+        total_bps = total_bps.checked_add(params.bps).unwrap();
+        require!(total_bps <= BPS_DENOMINATOR, InvalidProceedsSplits);
+
+        split.recipient = params.recipient;
+        split.bps = params.bps;
+    }
+
+    // proceeds of native sales can go straight to a wallet
+    let token_quote = ctx.accounts.token_quote.key();
+    let proceeds_destination = &ctx.accounts.proceeds_destination;
+    if !(token_quote == spl_token::native_mint::ID && is_native_destination(proceeds_destination)) {
+        let proceeds_destination = Account::<TokenAccount>::try_from(proceeds_destination)?;
+        require!(
+            proceeds_destination.mint == token_quote,
+            InvalidQuoteAccount
+        );
+    }
+
     let bond_sale = &mut ctx.accounts.bond_sale.load_init()?;
     let mut state = ctx.accounts.state.load_mut()?;
 
@@ -93,10 +148,66 @@ pub fn handler(
         vesting_time,
         id: state.next_bond_sale,
         next_bond: 0,
+        soft_cap: TokenAmount::new(soft_cap),
+        quote_raised: TokenAmount::new(0),
+        hard_cap: TokenAmount::new(hard_cap),
+        status: STATUS_ACTIVE,
+        proceeds_vesting_time,
+        proceeds_destination: ctx.accounts.proceeds_destination.key(),
+        proceeds_splits: splits,
+        referral_fee: Decimal::new(0),
+        referral_amount: TokenAmount::new(0),
+        fee_mode: FEE_MODE_QUOTE,
+        bond_fee: Decimal::new(0),
+        bond_fee_amount: TokenAmount::new(0),
+        supply_mode: match mint_on_demand {
+            true => SUPPLY_MODE_MINT,
+            false => SUPPLY_MODE_TRANSFER,
+        },
+        minted_amount: TokenAmount::new(0),
+        sale_quote_raised: TokenAmount::new(0),
+        sale_quote_count: 0,
+        price_mode: PRICE_MODE_FIXED,
+        oracle: Pubkey::default(),
+        oracle_discount: Decimal::new(0),
+        oracle_max_staleness: 0,
+        oracle_max_confidence: Decimal::new(0),
+        control_variable: Decimal::new(0),
+        total_debt: TokenAmount::new(0),
+        last_decay: 0,
+        max_payout: TokenAmount::new(0),
+        max_debt: TokenAmount::new(0),
+        adjustment_target: Decimal::new(0),
+        adjustment_rate: Decimal::new(0),
+        adjustment_buffer: 0,
+        last_adjustment: 0,
+        velocity_gain: Decimal::new(0),
+        min_velocity: Decimal::new(0),
+        max_velocity: Decimal::new(0),
+        ceil_mode: CEIL_MODE_CAP,
+        price_cumulative: 0,
+        last_observation: current_time,
+        last_observed_price: Decimal::new(floor_price),
+        volume_tiers: [VolumeTier::default(); MAX_VOLUME_TIERS],
+        vesting_terms: [VestingTerm::default(); MAX_VESTING_TERMS],
+        oracle_program: Pubkey::default(),
     };
 
     state.next_bond_sale += 1;
 
-    token::transfer(ctx.accounts.transfer_bond(), supply)?;
+    // the payer hands the mint authority over instead of transferring the supply
+    if mint_on_demand {
+        require!(
+            ctx.accounts.token_bond.mint_authority == COption::Some(ctx.accounts.payer.key()),
+            InvalidMintAuthority
+        );
+        token::set_authority(
+            ctx.accounts.set_mint_authority(),
+            AuthorityType::MintTokens,
+            Some(ctx.accounts.authority.key()),
+        )?;
+    } else {
+        token::transfer(ctx.accounts.transfer_bond(), supply)?;
+    }
     Ok(())
 }
@@ -1,21 +1,63 @@
+pub mod add_sale_quote;
+pub mod change_ceil_mode;
 pub mod change_fee;
+pub mod change_fee_mode;
+pub mod change_price_mode;
+pub mod change_referral_fee;
 pub mod change_up_bound;
 pub mod change_velocity;
 pub mod claim_bond;
+pub mod claim_bond_delegated;
 pub mod claim_quote;
+pub mod claim_referral;
+pub mod claim_sale_quote;
+pub mod close_bond;
 pub mod create_bond;
+pub mod create_bond_with_sale_quote;
+pub mod create_fee_vault;
+pub mod create_price_history;
+pub mod create_referral;
 pub mod create_state;
 pub mod end_bond_sale;
 pub mod init_bond_sale;
+pub mod poke_price;
+pub mod refund_bond;
+pub mod set_bond_delegate;
+pub mod set_debt_pricing;
+pub mod set_velocity_controller;
+pub mod set_vesting_terms;
+pub mod set_volume_tiers;
+pub mod withdraw_bond_fee;
 pub mod withdraw_fee;
 
+pub use add_sale_quote::*;
+pub use change_ceil_mode::*;
 pub use change_fee::*;
+pub use change_fee_mode::*;
+pub use change_price_mode::*;
+pub use change_referral_fee::*;
 pub use change_up_bound::*;
 pub use change_velocity::*;
 pub use claim_bond::*;
+pub use claim_bond_delegated::*;
 pub use claim_quote::*;
+pub use claim_referral::*;
+pub use claim_sale_quote::*;
+pub use close_bond::*;
 pub use create_bond::*;
+pub use create_bond_with_sale_quote::*;
+pub use create_fee_vault::*;
+pub use create_price_history::*;
+pub use create_referral::*;
 pub use create_state::*;
 pub use end_bond_sale::*;
 pub use init_bond_sale::*;
+pub use poke_price::*;
+pub use refund_bond::*;
+pub use set_bond_delegate::*;
+pub use set_debt_pricing::*;
+pub use set_velocity_controller::*;
+pub use set_vesting_terms::*;
+pub use set_volume_tiers::*;
+pub use withdraw_bond_fee::*;
 pub use withdraw_fee::*;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::math::apply_price_decay;
+use crate::structs::{BondSale, PriceHistory};
+use crate::utils::get_current_timestamp;
+
+#[derive(Accounts)]
+pub struct PokePrice<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = oracle.key() == bond_sale.load()?.oracle
+    )]
+    pub oracle: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<PokePrice>) -> ProgramResult {
+    let current_time = get_current_timestamp();
+    let bond_sale = &mut ctx.accounts.bond_sale.load_mut()?;
+
+    bond_sale.refresh_floor_price(&ctx.accounts.oracle, current_time)?;
+    apply_price_decay(bond_sale, current_time);
+
+    // snapshot for the price history when one is passed
+    if let Some(price_history_info) = ctx.remaining_accounts.first() {
+        let price_history_loader = AccountLoader::<PriceHistory>::try_from(price_history_info)?;
+        let mut price_history = price_history_loader.load_mut()?;
+        require!(
+            price_history.bond_sale == ctx.accounts.bond_sale.key(),
+            InvalidPriceHistory
+        );
+
+        price_history.record(bond_sale.last_observation, bond_sale.price_cumulative);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token;
+use anchor_spl::token::{transfer, TokenAccount, Transfer};
+
+use crate::{
+    get_signer,
+    interfaces::{TransferQuote, UnwrapAccounts, UnwrapQuote},
+    structs::{Bond, BondSale, State},
+    utils::{close, get_current_timestamp, is_native_destination, transfer_unwrapped},
+    SEED,
+};
+
+#[derive(Accounts)]
+pub struct RefundBond<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        constraint = bond.load()?.bond_sale == bond_sale.key()
+    )]
+    pub bond: AccountLoader<'info, Bond>,
+    #[account(mut,
+        constraint = token_quote_account.key() == bond_sale.load()?.token_quote_account
+    )]
+    pub token_quote_account: Account<'info, TokenAccount>,
+    // the owner itself for native sales
+    #[account(mut)]
+    pub owner_quote_account: AccountInfo<'info>,
+    #[account(mut,
+        constraint = owner.key() == bond.load()?.owner
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut,
+        constraint = rent_payer.key() == bond.load()?.rent_payer
+    )]
+    pub rent_payer: AccountInfo<'info>,
+    #[account(
+        constraint = token_quote.key() == bond_sale.load()?.token_quote
+    )]
+    pub token_quote: AccountInfo<'info>,
+    #[account(mut, seeds = [b"unwrapv1", bond_sale.key().as_ref()], bump)]
+    pub unwrap_account: AccountInfo<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> TransferQuote<'info> for RefundBond<'info> {
+    fn transfer_quote(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_account.to_account_info(),
+                to: self.owner_quote_account.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> UnwrapQuote<'info> for RefundBond<'info> {
+    fn unwrap_quote(&self) -> UnwrapAccounts<'info> {
+        UnwrapAccounts {
+            from: self.token_quote_account.to_account_info(),
+            unwrap_account: self.unwrap_account.clone(),
+            token_quote: self.token_quote.clone(),
+            destination: self.owner_quote_account.clone(),
+            payer: self.owner.to_account_info(),
+            authority: self.authority.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.to_account_info(),
+        }
+    }
+}
+
+pub fn handler(ctx: Context<RefundBond>) -> ProgramResult {
+    {
+        let bond = ctx.accounts.bond.load()?;
+        let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+        let state = ctx.accounts.state.load()?;
+
+        let current_time = get_current_timestamp();
+        require!(
+            bond_sale.is_soft_cap_failed(current_time),
+            RefundNotAvailable
+        );
+
+        bond_sale.quote_amount -= bond.quote_amount - bond.fee_amount;
+        bond_sale.fee_amount -= bond.fee_amount - bond.referral_amount;
+        bond_sale.referral_amount -= bond.referral_amount;
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        let owner_quote_account = &ctx.accounts.owner_quote_account;
+        if bond_sale.is_native() && is_native_destination(owner_quote_account) {
+            require!(
+                owner_quote_account.key() == ctx.accounts.owner.key(),
+                InvalidQuoteAccount
+            );
+            let bond_sale_key = ctx.accounts.bond_sale.key();
+            let unwrap_bump = *ctx.bumps.get("unwrap_account").unwrap();
+            let unwrap_signer: &[&[&[u8]]] =
+                &[&[b"unwrapv1", bond_sale_key.as_ref(), &[unwrap_bump]]];
+            transfer_unwrapped(ctx.accounts, bond.quote_amount.v, unwrap_signer, signer)?;
+        } else {
+            let owner_quote_account = Account::<TokenAccount>::try_from(owner_quote_account)?;
+            require!(
+                owner_quote_account.owner == ctx.accounts.owner.key()
+                    && owner_quote_account.mint == bond_sale.token_quote,
+                InvalidQuoteAccount
+            );
+            transfer(
+                ctx.accounts.transfer_quote().with_signer(signer),
+                bond.quote_amount.v,
+            )?;
+        }
+    }
+
+    close(
+        ctx.accounts.bond.to_account_info(),
+        ctx.accounts.rent_payer.to_account_info(),
+    )
+}
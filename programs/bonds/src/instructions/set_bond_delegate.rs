@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{Bond, Decimal, MAX_DELEGATE_TIP};
+
+#[derive(Accounts)]
+pub struct SetBondDelegate<'info> {
+    #[account(mut)]
+    pub bond: AccountLoader<'info, Bond>,
+    #[account(
+        constraint = owner.key() == bond.load()?.owner
+    )]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetBondDelegate>, delegate: Pubkey, tip: u128) -> ProgramResult {
+    let mut bond = ctx.accounts.bond.load_mut()?;
+    let tip = Decimal::new(tip);
+    require!(tip <= MAX_DELEGATE_TIP, DelegateTipExceeded);
+
+    bond.delegate = delegate;
+    bond.delegate_tip = tip;
+
+    Ok(())
+}
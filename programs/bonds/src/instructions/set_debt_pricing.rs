@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, Decimal, TokenAmount, PRICE_MODE_DEBT};
+use crate::utils::get_current_timestamp;
+
+#[derive(Accounts)]
+pub struct SetDebtPricing<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = payer.key() == bond_sale.load()?.payer
+    )]
+    pub payer: Signer<'info>,
+}
+
+// price follows control_variable * debt / supply, the floor stays the minimum price
+// zero max_payout or max_debt means no limit, the control variable moves by adjustment_rate
+// towards adjustment_target once every adjustment_buffer seconds
+pub fn handler(
+    ctx: Context<SetDebtPricing>,
+    control_variable: u128,
+    max_payout: u64,
+    max_debt: u64,
+    adjustment_target: u128,
+    adjustment_rate: u128,
+    adjustment_buffer: u64,
+) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+    let current_time = get_current_timestamp();
+
+    require!(control_variable != 0, InvalidPriceMode);
+
+    // debt already taken keeps decaying from now on
+    if bond_sale.price_mode == PRICE_MODE_DEBT {
+        bond_sale.add_debt(TokenAmount::new(0), current_time);
+    }
+
+    bond_sale.price_mode = PRICE_MODE_DEBT;
+    bond_sale.control_variable = Decimal::new(control_variable);
+    bond_sale.max_payout = TokenAmount::new(max_payout);
+    bond_sale.max_debt = TokenAmount::new(max_debt);
+    bond_sale.adjustment_target = Decimal::new(adjustment_target);
+    bond_sale.adjustment_rate = Decimal::new(adjustment_rate);
+    bond_sale.adjustment_buffer = adjustment_buffer;
+    bond_sale.last_adjustment = current_time;
+    bond_sale.last_decay = current_time;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, Decimal};
+
+#[derive(Accounts)]
+pub struct SetVelocityController<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = payer.key() == bond_sale.load()?.payer
+    )]
+    pub payer: Signer<'info>,
+}
+
+// before each purchase velocity moves by gain * (elapsed fraction - sold fraction) within the bounds,
+// zero gain turns the controller off
+pub fn handler(
+    ctx: Context<SetVelocityController>,
+    gain: u128,
+    min_velocity: u128,
+    max_velocity: u128,
+) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(min_velocity <= max_velocity, InvalidVelocityBounds);
+
+    bond_sale.velocity_gain = Decimal::new(gain);
+    bond_sale.min_velocity = Decimal::new(min_velocity);
+    bond_sale.max_velocity = Decimal::new(max_velocity);
+
+    if gain != 0 {
+        bond_sale.velocity = Decimal::new(bond_sale.velocity.v.clamp(min_velocity, max_velocity));
+    }
+
+    Ok(())
+}
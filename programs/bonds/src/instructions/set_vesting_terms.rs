@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{BondSale, Decimal, VestingTerm, VestingTermParams, MAX_VESTING_TERMS};
+
+#[derive(Accounts)]
+pub struct SetVestingTerms<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = payer.key() == bond_sale.load()?.payer
+    )]
+    pub payer: Signer<'info>,
+}
+
+// replaces every term, buyers can always fall back to the sale vesting time
+pub fn handler(
+    ctx: Context<SetVestingTerms>,
+    vesting_terms: Vec<VestingTermParams>,
+) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(
+        vesting_terms.len() <= MAX_VESTING_TERMS,
+        InvalidVestingTerms
+    );
+
+    let mut terms = [VestingTerm::default(); MAX_VESTING_TERMS];
+    let mut previous_vesting_time = 0;
+    for (term, params) in terms.iter_mut().zip(vesting_terms.iter()) {
+        require!(
+            params.vesting_time > previous_vesting_time,
+            InvalidVestingTerms
+        );
+        require!(params.discount < Decimal::one().v, InvalidVestingTerms);
+        previous_vesting_time = params.vesting_time;
+
+        *term = VestingTerm {
+            vesting_time: params.vesting_time,
+            discount: Decimal::new(params.discount),
+        };
+    }
+
+    bond_sale.vesting_terms = terms;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::structs::{
+    BondSale, Decimal, TokenAmount, VolumeTier, VolumeTierParams, MAX_VOLUME_TIERS,
+};
+
+#[derive(Accounts)]
+pub struct SetVolumeTiers<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = payer.key() == bond_sale.load()?.payer
+    )]
+    pub payer: Signer<'info>,
+}
+
+// replaces every tier, an empty list turns the discounts off
+pub fn handler(ctx: Context<SetVolumeTiers>, volume_tiers: Vec<VolumeTierParams>) -> ProgramResult {
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    require!(volume_tiers.len() <= MAX_VOLUME_TIERS, InvalidVolumeTiers);
+
+    let mut tiers = [VolumeTier::default(); MAX_VOLUME_TIERS];
+    let mut previous_min_amount = 0;
+    for (tier, params) in tiers.iter_mut().zip(volume_tiers.iter()) {
+        require!(params.min_amount > previous_min_amount, InvalidVolumeTiers);
+        require!(params.discount < Decimal::one().v, InvalidVolumeTiers);
+        previous_min_amount = params.min_amount;
+
+        *tier = VolumeTier {
+            min_amount: TokenAmount::new(params.min_amount),
+            discount: Decimal::new(params.discount),
+        };
+    }
+
+    bond_sale.volume_tiers = tiers;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+use crate::{
+    get_signer,
+    interfaces::TransferFee,
+    structs::{BondSale, State, TokenAmount},
+    SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawBondFee<'info> {
+    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        constraint = token_bond_account.key() == bond_sale.load()?.token_bond_account,
+    )]
+    pub token_bond_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = admin_bond_account.mint == token_bond_account.mint,
+        constraint = admin_bond_account.owner == admin.key(),
+    )]
+    pub admin_bond_account: Account<'info, TokenAccount>,
+    #[account(
+        constraint = admin.key() == state.load()?.admin,
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        constraint = authority.key() == state.load()?.authority,
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> TransferFee<'info> for WithdrawBondFee<'info> {
+    fn transfer_fee(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_bond_account.to_account_info(),
+                to: self.admin_bond_account.to_account_info(),
+                authority: self.authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawBondFee>) -> ProgramResult {
+    let state = ctx.accounts.state.load()?;
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+
+    let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+    token::transfer(
+        ctx.accounts.transfer_fee().with_signer(signer),
+        bond_sale.bond_fee_amount.v,
+    )?;
+
+    bond_sale.bond_fee_amount = TokenAmount::new(0);
+
+    Ok(())
+}
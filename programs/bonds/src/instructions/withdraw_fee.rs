@@ -3,23 +3,22 @@ use anchor_spl::token::{self, TokenAccount, Transfer};
 
 use crate::{
     get_signer,
-    interfaces::TransferQuote,
-    structs::{BondSale, State, TokenAmount},
+    interfaces::TransferFee,
+    structs::{FeeVault, State, TokenAmount},
     SEED,
 };
 
 #[derive(Accounts)]
 pub struct WithdrawFee<'info> {
-    #[account(seeds = [b"statev1"], bump = state.load()?.bump)]
+    #[account(mut, seeds = [b"statev1"], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
-    #[account(mut)]
-    pub bond_sale: AccountLoader<'info, BondSale>,
+    pub fee_vault: AccountLoader<'info, FeeVault>,
     #[account(mut,
-        constraint = token_quote_account.key() == bond_sale.load()?.token_quote_account,
+        constraint = fee_vault_token_account.key() == fee_vault.load()?.token_account,
     )]
-    pub token_quote_account: Account<'info, TokenAccount>,
+    pub fee_vault_token_account: Account<'info, TokenAccount>,
     #[account(mut,
-        constraint = admin_quote_account.mint == token_quote_account.mint,
+        constraint = admin_quote_account.mint == fee_vault_token_account.mint,
         constraint = admin_quote_account.owner == admin.key(),
     )]
     pub admin_quote_account: Account<'info, TokenAccount>,
@@ -31,15 +30,16 @@ pub struct WithdrawFee<'info> {
         constraint = authority.key() == state.load()?.authority,
     )]
     pub authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
     pub token_program: AccountInfo<'info>,
 }
 
-impl<'info> TransferQuote<'info> for WithdrawFee<'info> {
-    fn transfer_quote(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+impl<'info> TransferFee<'info> for WithdrawFee<'info> {
+    fn transfer_fee(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
             self.token_program.to_account_info(),
             Transfer {
-                from: self.token_quote_account.to_account_info(),
+                from: self.fee_vault_token_account.to_account_info(),
                 to: self.admin_quote_account.to_account_info(),
                 authority: self.authority.to_account_info().clone(),
             },
@@ -48,17 +48,14 @@ impl<'info> TransferQuote<'info> for WithdrawFee<'info> {
 }
 
 pub fn handler(ctx: Context<WithdrawFee>) -> ProgramResult {
-    let state = ctx.accounts.state.load()?;
-    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+    let mut state = ctx.accounts.state.load_mut()?;
 
     let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+    let amount = TokenAmount::new(ctx.accounts.fee_vault_token_account.amount);
 
-    token::transfer(
-        ctx.accounts.transfer_quote().with_signer(signer),
-        bond_sale.fee_amount.v,
-    )?;
+    token::transfer(ctx.accounts.transfer_fee().with_signer(signer), amount.v)?;
 
-    bond_sale.fee_amount = TokenAmount::new(0);
+    state.total_withdrawn += amount;
 
     Ok(())
 }
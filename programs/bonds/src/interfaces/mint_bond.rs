@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{MintTo, SetAuthority};
+
+pub trait MintBond<'info> {
+    fn mint_bond(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>>;
+}
+
+pub trait SetMintAuthority<'info> {
+    fn set_mint_authority(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>>;
+}
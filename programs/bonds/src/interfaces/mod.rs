@@ -1,5 +1,9 @@
 pub mod close_token_account;
+pub mod mint_bond;
 pub mod transfer_tokens;
+pub mod unwrap_quote;
 
 pub use close_token_account::*;
+pub use mint_bond::*;
 pub use transfer_tokens::*;
+pub use unwrap_quote::*;
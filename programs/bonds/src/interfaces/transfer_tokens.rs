@@ -11,3 +11,14 @@ pub trait TransferQuote<'info> {
 pub trait TransferFee<'info> {
     fn transfer_fee(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>>;
 }
+
+pub trait TransferTip<'info> {
+    fn transfer_tip(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>>;
+}
+
+pub trait TransferSplit<'info> {
+    fn transfer_split(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>>;
+}
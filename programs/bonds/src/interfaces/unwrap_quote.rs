@@ -0,0 +1,17 @@
+use anchor_lang::prelude::AccountInfo;
+
+pub struct UnwrapAccounts<'info> {
+    pub from: AccountInfo<'info>,
+    pub unwrap_account: AccountInfo<'info>,
+    pub token_quote: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+pub trait UnwrapQuote<'info> {
+    fn unwrap_quote(&self) -> UnwrapAccounts<'info>;
+}
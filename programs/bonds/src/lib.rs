@@ -11,6 +11,7 @@ use anchor_lang::prelude::*;
 
 use errors::*;
 use instructions::*;
+use structs::{ProceedsSplitParams, VestingTermParams, VolumeTierParams};
 
 declare_id!("DojjMwd2tErELy9vuLs7Jb6JW7FBJEh4f25wibHp3HCm");
 pub const SEED: &str = "Bonds";
@@ -23,6 +24,7 @@ pub mod bonds {
         instructions::create_state::handler(ctx, nonce)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init_bond_sale(
         ctx: Context<InitBondSale>,
         floor_price: u128,
@@ -31,6 +33,11 @@ pub mod bonds {
         supply: u64,
         duration: u64,
         vesting_time: u64,
+        soft_cap: u64,
+        hard_cap: u64,
+        proceeds_vesting_time: u64,
+        proceeds_splits: Vec<ProceedsSplitParams>,
+        mint_on_demand: bool,
     ) -> ProgramResult {
         instructions::init_bond_sale::handler(
             ctx,
@@ -40,14 +47,43 @@ pub mod bonds {
             supply,
             duration,
             vesting_time,
+            soft_cap,
+            hard_cap,
+            proceeds_vesting_time,
+            proceeds_splits,
+            mint_on_demand,
         )
     }
 
-    pub fn create_bond(ctx: Context<CreateBond>, amount: u64, price_limit: u128) -> ProgramResult {
-        instructions::create_bond::handler(ctx, amount, price_limit)
+    pub fn create_bond<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBond<'info>>,
+        amount: u64,
+        price_limit: u128,
+        expires_at: Option<u64>,
+        vesting_term: Option<u8>,
+    ) -> ProgramResult {
+        instructions::create_bond::handler(ctx, amount, price_limit, expires_at, vesting_term)
     }
 
-    pub fn end_bond_sale(ctx: Context<EndBondSale>) -> ProgramResult {
+    pub fn create_bond_exact_in<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBond<'info>>,
+        quote_amount: u64,
+        min_bond_out: u64,
+        expires_at: Option<u64>,
+        vesting_term: Option<u8>,
+    ) -> ProgramResult {
+        instructions::create_bond::exact_in_handler(
+            ctx,
+            quote_amount,
+            min_bond_out,
+            expires_at,
+            vesting_term,
+        )
+    }
+
+    pub fn end_bond_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, EndBondSale<'info>>,
+    ) -> ProgramResult {
         instructions::end_bond_sale::handler(ctx)
     }
 
@@ -59,19 +95,169 @@ pub mod bonds {
         instructions::change_up_bound::handler(ctx, up_bound)
     }
 
-    pub fn claim_quote(ctx: Context<ClaimQuote>) -> ProgramResult {
+    pub fn claim_quote<'info>(ctx: Context<'_, '_, '_, 'info, ClaimQuote<'info>>) -> ProgramResult {
         instructions::claim_quote::handler(ctx)
     }
 
-    pub fn claim_bond(ctx: Context<ClaimBond>) -> ProgramResult {
-        instructions::claim_bond::handler(ctx)
+    pub fn refund_bond(ctx: Context<RefundBond>) -> ProgramResult {
+        instructions::refund_bond::handler(ctx)
+    }
+
+    pub fn claim_bond(ctx: Context<ClaimBond>, amount: Option<u64>) -> ProgramResult {
+        instructions::claim_bond::handler(ctx, amount)
+    }
+
+    pub fn close_bond(ctx: Context<CloseBond>) -> ProgramResult {
+        instructions::close_bond::handler(ctx)
+    }
+
+    pub fn set_bond_delegate(
+        ctx: Context<SetBondDelegate>,
+        delegate: Pubkey,
+        tip: u128,
+    ) -> ProgramResult {
+        instructions::set_bond_delegate::handler(ctx, delegate, tip)
+    }
+
+    pub fn claim_bond_delegated(ctx: Context<ClaimBondDelegated>) -> ProgramResult {
+        instructions::claim_bond_delegated::handler(ctx)
     }
 
     pub fn change_fee(ctx: Context<ChangeFee>, new_fee: u128) -> ProgramResult {
         instructions::change_fee::handler(ctx, new_fee)
     }
 
+    pub fn change_fee_mode(
+        ctx: Context<ChangeFeeMode>,
+        fee_mode: u8,
+        bond_fee: u128,
+    ) -> ProgramResult {
+        instructions::change_fee_mode::handler(ctx, fee_mode, bond_fee)
+    }
+
+    pub fn create_fee_vault(ctx: Context<CreateFeeVault>) -> ProgramResult {
+        instructions::create_fee_vault::handler(ctx)
+    }
+
     pub fn withdraw_fee(ctx: Context<WithdrawFee>) -> ProgramResult {
         instructions::withdraw_fee::handler(ctx)
     }
+
+    pub fn withdraw_bond_fee(ctx: Context<WithdrawBondFee>) -> ProgramResult {
+        instructions::withdraw_bond_fee::handler(ctx)
+    }
+
+    pub fn change_referral_fee(
+        ctx: Context<ChangeReferralFee>,
+        referral_fee: u128,
+    ) -> ProgramResult {
+        instructions::change_referral_fee::handler(ctx, referral_fee)
+    }
+
+    pub fn create_referral(ctx: Context<CreateReferral>) -> ProgramResult {
+        instructions::create_referral::handler(ctx)
+    }
+
+    pub fn claim_referral(ctx: Context<ClaimReferral>) -> ProgramResult {
+        instructions::claim_referral::handler(ctx)
+    }
+
+    pub fn add_sale_quote(ctx: Context<AddSaleQuote>, rate: u128) -> ProgramResult {
+        instructions::add_sale_quote::handler(ctx, rate)
+    }
+
+    pub fn create_bond_with_sale_quote(
+        ctx: Context<CreateBondWithSaleQuote>,
+        amount: u64,
+        price_limit: u128,
+        expires_at: Option<u64>,
+        vesting_term: Option<u8>,
+    ) -> ProgramResult {
+        instructions::create_bond_with_sale_quote::handler(
+            ctx,
+            amount,
+            price_limit,
+            expires_at,
+            vesting_term,
+        )
+    }
+
+    pub fn claim_sale_quote<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimSaleQuote<'info>>,
+    ) -> ProgramResult {
+        instructions::claim_sale_quote::handler(ctx)
+    }
+
+    pub fn change_price_mode(
+        ctx: Context<ChangePriceMode>,
+        price_mode: u8,
+        discount: u128,
+        max_staleness: u64,
+        max_confidence: u128,
+        oracle_program: Pubkey,
+    ) -> ProgramResult {
+        instructions::change_price_mode::handler(
+            ctx,
+            price_mode,
+            discount,
+            max_staleness,
+            max_confidence,
+            oracle_program,
+        )
+    }
+
+    pub fn set_debt_pricing(
+        ctx: Context<SetDebtPricing>,
+        control_variable: u128,
+        max_payout: u64,
+        max_debt: u64,
+        adjustment_target: u128,
+        adjustment_rate: u128,
+        adjustment_buffer: u64,
+    ) -> ProgramResult {
+        instructions::set_debt_pricing::handler(
+            ctx,
+            control_variable,
+            max_payout,
+            max_debt,
+            adjustment_target,
+            adjustment_rate,
+            adjustment_buffer,
+        )
+    }
+
+    pub fn set_velocity_controller(
+        ctx: Context<SetVelocityController>,
+        gain: u128,
+        min_velocity: u128,
+        max_velocity: u128,
+    ) -> ProgramResult {
+        instructions::set_velocity_controller::handler(ctx, gain, min_velocity, max_velocity)
+    }
+
+    pub fn change_ceil_mode(ctx: Context<ChangeCeilMode>, ceil_mode: u8) -> ProgramResult {
+        instructions::change_ceil_mode::handler(ctx, ceil_mode)
+    }
+
+    pub fn create_price_history(ctx: Context<CreatePriceHistory>) -> ProgramResult {
+        instructions::create_price_history::handler(ctx)
+    }
+
+    pub fn poke_price(ctx: Context<PokePrice>) -> ProgramResult {
+        instructions::poke_price::handler(ctx)
+    }
+
+    pub fn set_volume_tiers(
+        ctx: Context<SetVolumeTiers>,
+        volume_tiers: Vec<VolumeTierParams>,
+    ) -> ProgramResult {
+        instructions::set_volume_tiers::handler(ctx, volume_tiers)
+    }
+
+    pub fn set_vesting_terms(
+        ctx: Context<SetVestingTerms>,
+        vesting_terms: Vec<VestingTermParams>,
+    ) -> ProgramResult {
+        instructions::set_vesting_terms::handler(ctx, vesting_terms)
+    }
 }
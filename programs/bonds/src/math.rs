@@ -1,12 +1,17 @@
 use std::convert::TryInto;
 
-use crate::structs::{BondSale, Decimal, TokenAmount};
+use crate::structs::{BondSale, Decimal, TokenAmount, VestingTerm, PRICE_MODE_DEBT};
 
-pub fn calculate_new_price(
-    bond_sale: &mut BondSale,
+fn calculate_price_and_jump(
+    bond_sale: &BondSale,
     current_time: u64,
     buy_amount: TokenAmount,
-) -> Decimal {
+) -> (Decimal, Decimal) {
+    // debt priced sales do not move with the size of a single purchase
+    if bond_sale.price_mode == PRICE_MODE_DEBT {
+        return (bond_sale.get_debt_price(current_time), Decimal::new(0));
+    }
+
     let delta_time = current_time - bond_sale.last_trade;
     let sale_time = bond_sale.end_time - bond_sale.start_time;
     let time_ratio = Decimal::from_integer(delta_time.try_into().unwrap())
@@ -21,16 +26,154 @@ pub fn calculate_new_price(
     };
     let jump = supply_ratio * bond_sale.up_bound * bond_sale.floor_price;
 
-    bond_sale.previous_price = price + jump;
-    bond_sale.remaining_amount = bond_sale.remaining_amount - buy_amount;
+    (cap_price(bond_sale, price), jump)
+}
+
+// curve prices never go above the ceiling, debt priced sales are bounded by max_debt instead
+fn cap_price(bond_sale: &BondSale, price: Decimal) -> Decimal {
+    if bond_sale.price_mode == PRICE_MODE_DEBT {
+        return price;
+    }
+
+    let ceil_price = bond_sale.calculate_ceil_price();
+    match price > ceil_price {
+        true => ceil_price,
+        false => price,
+    }
+}
+
+// price after buy_amount before it is capped, sales in reject mode refuse to cross the ceiling
+pub fn calculate_end_price(
+    bond_sale: &BondSale,
+    current_time: u64,
+    buy_amount: TokenAmount,
+) -> Decimal {
+    let (price, jump) = calculate_price_and_jump(bond_sale, current_time, buy_amount);
+
+    price + jump
+}
+
+pub fn calculate_sell_price(
+    bond_sale: &BondSale,
+    current_time: u64,
+    buy_amount: TokenAmount,
+) -> Decimal {
+    let (price, jump) = calculate_price_and_jump(bond_sale, current_time, buy_amount);
+
+    cap_price(bond_sale, Decimal::from_decimal(50, 2) * jump + price)
+}
+
+// accumulates the current price up to current_time without trading
+pub fn update_price_cumulative(bond_sale: &mut BondSale, current_time: u64) {
+    let price = calculate_sell_price(bond_sale, current_time, TokenAmount::new(0));
+    bond_sale.accumulate_price(price, current_time);
+}
+
+pub fn calculate_new_price(
+    bond_sale: &mut BondSale,
+    current_time: u64,
+    buy_amount: TokenAmount,
+) -> Decimal {
+    update_price_cumulative(bond_sale, current_time);
+    let (price, jump) = calculate_price_and_jump(bond_sale, current_time, buy_amount);
+
+    if bond_sale.price_mode == PRICE_MODE_DEBT {
+        bond_sale.add_debt(buy_amount, current_time);
+    }
+    bond_sale.previous_price = cap_price(bond_sale, price + jump);
+    bond_sale.remaining_amount = { bond_sale.remaining_amount } - buy_amount;
     bond_sale.last_trade = current_time;
+    bond_sale.last_observed_price =
+        calculate_sell_price(bond_sale, current_time, TokenAmount::new(0));
+
+    cap_price(bond_sale, Decimal::from_decimal(50, 2) * jump + price)
+}
 
-    Decimal::from_decimal(50, 2) * jump + price
+// moves previous_price to the current price without a purchase, decay is the same either way
+pub fn apply_price_decay(bond_sale: &mut BondSale, current_time: u64) {
+    calculate_new_price(bond_sale, current_time, TokenAmount::new(0));
+}
+
+pub fn calculate_quote_amount(sell_price: Decimal, buy_amount: TokenAmount) -> TokenAmount {
+    buy_amount.big_mul(sell_price).to_token_ceil()
+}
+
+// largest amount of bond tokens that can be bought for quote_amount
+// the cost only grows within a volume tier and drops where a tier starts, so tiers are searched
+// from the largest one and the first tier affordable at its start holds the answer
+pub fn calculate_buy_amount(
+    bond_sale: &BondSale,
+    current_time: u64,
+    quote_amount: TokenAmount,
+    vesting_term: &VestingTerm,
+) -> TokenAmount {
+    let is_affordable = |amount: u64| {
+        let buy_amount = TokenAmount::new(amount);
+        let sell_price = vesting_term.apply_discount(bond_sale.apply_volume_discount(
+            calculate_sell_price(bond_sale, current_time, buy_amount),
+            buy_amount,
+        ));
+        calculate_quote_amount(sell_price, buy_amount) <= quote_amount
+    };
+
+    let remaining = bond_sale.remaining_amount.v;
+    let tier_starts = { bond_sale.volume_tiers }
+        .iter()
+        .filter(|tier| tier.is_active())
+        .map(|tier| tier.min_amount.v)
+        .collect::<Vec<u64>>();
+    let mut upper = remaining;
+
+    for &start in tier_starts.iter().rev().chain(std::iter::once(&0)) {
+        if start > remaining || !is_affordable(start) {
+            upper = upper.min(start.saturating_sub(1));
+            continue;
+        }
+
+        let mut lower = start;
+        while lower < upper {
+            let middle = upper - (upper - lower) / 2;
+            match is_affordable(middle) {
+                true => lower = middle,
+                false => upper = middle - 1,
+            }
+        }
+        return TokenAmount::new(lower);
+    }
+
+    TokenAmount::new(0)
+}
+
+// largest part of buy_amount that keeps the total raised within the hard cap
+pub fn calculate_capped_amount(
+    bond_sale: &BondSale,
+    current_time: u64,
+    buy_amount: TokenAmount,
+    vesting_term: &VestingTerm,
+) -> TokenAmount {
+    if bond_sale.hard_cap.is_zero() {
+        return buy_amount;
+    }
+    if bond_sale.is_hard_cap_reached() {
+        return TokenAmount::new(0);
+    }
+
+    let room = bond_sale.hard_cap - bond_sale.get_total_raised();
+    let quote_budget = room
+        .big_div(Decimal::one() - bond_sale.get_quote_fee())
+        .to_token_floor();
+    let capped_amount = calculate_buy_amount(bond_sale, current_time, quote_budget, vesting_term);
+
+    match capped_amount < buy_amount {
+        true => capped_amount,
+        false => buy_amount,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::structs::VolumeTier;
 
     #[test]
     #[should_panic]
@@ -229,4 +372,336 @@ mod tests {
             assert_eq!({ bond_sale.remaining_amount }, TokenAmount::new(8)); // 8 = 1_000_000 - 28 * 35714
         }
     }
+
+    #[test]
+    fn test_calculate_new_price_price_cumulative() {
+        let mut bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            previous_price: Decimal::from_integer(2),
+            up_bound: Decimal::from_decimal(50, 2),
+            velocity: Decimal::one(),
+            supply: TokenAmount::new(100),
+            remaining_amount: TokenAmount::new(100),
+            end_time: 100,
+            last_observed_price: Decimal::from_integer(2),
+            ..Default::default()
+        };
+
+        // the observed price follows the trade
+        calculate_new_price(&mut bond_sale, 0, TokenAmount::new(10));
+        assert_eq!({ bond_sale.price_cumulative }, 0);
+        assert_eq!(
+            { bond_sale.last_observed_price },
+            Decimal::from_decimal(210, 2)
+        );
+
+        // price decayed from 2.1 to 2 over 10 seconds
+        update_price_cumulative(&mut bond_sale, 10);
+        assert_eq!(
+            { bond_sale.price_cumulative },
+            Decimal::from_decimal(205, 1).v
+        );
+        assert_eq!({ bond_sale.last_observation }, 10);
+        assert_eq!({ bond_sale.last_observed_price }, Decimal::from_integer(2));
+        assert_eq!({ bond_sale.previous_price }, Decimal::from_decimal(210, 2));
+    }
+
+    #[test]
+    fn test_apply_price_decay() {
+        let bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            previous_price: Decimal::from_integer(3),
+            up_bound: Decimal::from_decimal(50, 2),
+            velocity: Decimal::one(),
+            supply: TokenAmount::new(100),
+            remaining_amount: TokenAmount::new(50),
+            end_time: 100,
+            last_observed_price: Decimal::from_integer(3),
+            ..Default::default()
+        };
+
+        let mut poked = bond_sale;
+        apply_price_decay(&mut poked, 40);
+        assert_eq!({ poked.previous_price }, Decimal::from_decimal(26, 1));
+        assert_eq!({ poked.last_trade }, 40);
+        assert_eq!({ poked.remaining_amount }, TokenAmount::new(50));
+        assert_eq!({ poked.last_observed_price }, Decimal::from_decimal(26, 1));
+        assert_eq!({ poked.price_cumulative }, Decimal::from_integer(112).v);
+
+        // a purchase pays the same after the poke
+        let mut unpoked = bond_sale;
+        assert_eq!(
+            calculate_new_price(&mut poked, 60, TokenAmount::new(10)),
+            calculate_new_price(&mut unpoked, 60, TokenAmount::new(10))
+        );
+        assert_eq!({ poked.previous_price }, { unpoked.previous_price });
+
+        // decays down to the floor
+        apply_price_decay(&mut poked, 200);
+        assert_eq!({ poked.previous_price }, Decimal::from_integer(2));
+    }
+
+    #[test]
+    fn test_calculate_new_price_debt_mode() {
+        let mut bond_sale = BondSale {
+            price_mode: PRICE_MODE_DEBT,
+            floor_price: Decimal::from_decimal(50, 2),
+            previous_price: Decimal::from_decimal(50, 2),
+            control_variable: Decimal::from_integer(4),
+            supply: TokenAmount::new(1000),
+            remaining_amount: TokenAmount::new(1000),
+            vesting_time: 100,
+            end_time: 1000,
+            ..Default::default()
+        };
+
+        // the first purchase pays the floor and becomes debt
+        let result = calculate_new_price(&mut bond_sale, 0, TokenAmount::new(250));
+        assert_eq!(result, Decimal::from_decimal(50, 2));
+        assert_eq!({ bond_sale.total_debt }, TokenAmount::new(250));
+
+        // price is the control variable times the debt ratio, whatever the size
+        let result = calculate_new_price(&mut bond_sale, 0, TokenAmount::new(500));
+        assert_eq!(result, Decimal::from_integer(1));
+        assert_eq!({ bond_sale.previous_price }, Decimal::from_integer(1));
+        assert_eq!({ bond_sale.total_debt }, TokenAmount::new(750));
+        assert_eq!({ bond_sale.remaining_amount }, TokenAmount::new(250));
+
+        // half of the debt decays over half of the vesting time
+        let result = calculate_sell_price(&bond_sale, 50, TokenAmount::new(1));
+        assert_eq!(result, Decimal::from_decimal(150, 2));
+    }
+
+    #[test]
+    fn test_calculate_new_price_ceiling() {
+        let bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            previous_price: Decimal::from_integer(3),
+            up_bound: Decimal::from_decimal(50, 2),
+            velocity: Decimal::one(),
+            supply: TokenAmount::new(100),
+            remaining_amount: TokenAmount::new(100),
+            end_time: 604800,
+            ..Default::default()
+        };
+
+        // a burst at the ceiling stays at the ceiling
+        {
+            let mut bond_sale = bond_sale;
+            assert_eq!(
+                calculate_end_price(&bond_sale, 0, TokenAmount::new(50)),
+                Decimal::from_decimal(350, 2)
+            );
+
+            let result = calculate_new_price(&mut bond_sale, 0, TokenAmount::new(50));
+            assert_eq!(result, Decimal::from_integer(3));
+            assert_eq!({ bond_sale.previous_price }, Decimal::from_integer(3));
+        }
+        // a previous price above the ceiling is capped as well
+        {
+            let bond_sale = BondSale {
+                previous_price: Decimal::from_integer(4),
+                ..bond_sale
+            };
+            assert_eq!(
+                calculate_sell_price(&bond_sale, 0, TokenAmount::new(0)),
+                Decimal::from_integer(3)
+            );
+        }
+        // no ceiling for debt pricing
+        {
+            let bond_sale = BondSale {
+                price_mode: PRICE_MODE_DEBT,
+                control_variable: Decimal::from_integer(16),
+                total_debt: TokenAmount::new(50),
+                vesting_time: 100,
+                ..bond_sale
+            };
+            assert_eq!(
+                calculate_sell_price(&bond_sale, 0, TokenAmount::new(10)),
+                Decimal::from_integer(8)
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_sell_price() {
+        let bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            previous_price: Decimal::from_integer(2),
+            up_bound: Decimal::from_decimal(50, 2),
+            velocity: Decimal::one(),
+            supply: TokenAmount::new(100),
+            remaining_amount: TokenAmount::new(20),
+            end_time: 604800,
+            ..Default::default()
+        };
+
+        let result = calculate_sell_price(&bond_sale, 0, TokenAmount::new(10));
+        assert_eq!(result, Decimal::from_decimal(205, 2));
+        // does not modify bond sale
+        assert_eq!({ bond_sale.previous_price }, Decimal::from_integer(2));
+        assert_eq!({ bond_sale.remaining_amount }, TokenAmount::new(20));
+    }
+
+    #[test]
+    fn test_calculate_buy_amount() {
+        let bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            previous_price: Decimal::from_integer(2),
+            up_bound: Decimal::from_decimal(50, 2),
+            velocity: Decimal::one(),
+            supply: TokenAmount::new(100),
+            remaining_amount: TokenAmount::new(20),
+            end_time: 604800,
+            ..Default::default()
+        };
+        // 10 tokens at 2.05
+        {
+            let result =
+                calculate_buy_amount(&bond_sale, 0, TokenAmount::new(21), &VestingTerm::default());
+            assert_eq!(result, TokenAmount::new(10));
+        }
+        // not enough for 11 tokens at 2.055
+        {
+            let result =
+                calculate_buy_amount(&bond_sale, 0, TokenAmount::new(22), &VestingTerm::default());
+            assert_eq!(result, TokenAmount::new(10));
+        }
+        // capped by remaining amount
+        {
+            let result = calculate_buy_amount(
+                &bond_sale,
+                0,
+                TokenAmount::new(1000),
+                &VestingTerm::default(),
+            );
+            assert_eq!(result, TokenAmount::new(20));
+        }
+        // nothing
+        {
+            let result =
+                calculate_buy_amount(&bond_sale, 0, TokenAmount::new(1), &VestingTerm::default());
+            assert_eq!(result, TokenAmount::new(0));
+        }
+        // 10 tokens at half of 2.05 with a term discount
+        {
+            let vesting_term = VestingTerm {
+                vesting_time: 100,
+                discount: Decimal::from_decimal(50, 2),
+            };
+            let result = calculate_buy_amount(&bond_sale, 0, TokenAmount::new(11), &vesting_term);
+            assert_eq!(result, TokenAmount::new(10));
+        }
+        // 10 tokens at half of 2.05 with a volume discount
+        {
+            let mut bond_sale = bond_sale;
+            bond_sale.volume_tiers[0] = VolumeTier {
+                min_amount: TokenAmount::new(10),
+                discount: Decimal::from_decimal(50, 2),
+            };
+            let result =
+                calculate_buy_amount(&bond_sale, 0, TokenAmount::new(11), &VestingTerm::default());
+            assert_eq!(result, TokenAmount::new(10));
+        }
+        // budget right at a tier start, 7 tokens cost 15 and 15 tokens cost 16 with the discount
+        {
+            let mut bond_sale = bond_sale;
+            bond_sale.volume_tiers[0] = VolumeTier {
+                min_amount: TokenAmount::new(15),
+                discount: Decimal::from_decimal(50, 2),
+            };
+            let result =
+                calculate_buy_amount(&bond_sale, 0, TokenAmount::new(16), &VestingTerm::default());
+            assert_eq!(result, TokenAmount::new(15));
+            // one short of the tier falls back below it
+            let result =
+                calculate_buy_amount(&bond_sale, 0, TokenAmount::new(15), &VestingTerm::default());
+            assert_eq!(result, TokenAmount::new(7));
+        }
+    }
+
+    #[test]
+    fn test_calculate_capped_amount() {
+        let bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            previous_price: Decimal::from_integer(2),
+            up_bound: Decimal::from_decimal(50, 2),
+            velocity: Decimal::one(),
+            supply: TokenAmount::new(100),
+            remaining_amount: TokenAmount::new(20),
+            end_time: 604800,
+            ..Default::default()
+        };
+        // no hard cap
+        {
+            let result = calculate_capped_amount(
+                &bond_sale,
+                0,
+                TokenAmount::new(20),
+                &VestingTerm::default(),
+            );
+            assert_eq!(result, TokenAmount::new(20));
+        }
+        // below hard cap
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(1000),
+                ..bond_sale
+            };
+            let result = calculate_capped_amount(
+                &bond_sale,
+                0,
+                TokenAmount::new(20),
+                &VestingTerm::default(),
+            );
+            assert_eq!(result, TokenAmount::new(20));
+        }
+        // partially filled, 10 tokens cost 21
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(121),
+                quote_raised: TokenAmount::new(100),
+                ..bond_sale
+            };
+            let result = calculate_capped_amount(
+                &bond_sale,
+                0,
+                TokenAmount::new(20),
+                &VestingTerm::default(),
+            );
+            assert_eq!(result, TokenAmount::new(10));
+        }
+        // partially filled with fee, 10 tokens cost 21 and 20 after fee
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(120),
+                quote_raised: TokenAmount::new(100),
+                fee: Decimal::from_decimal(1, 2),
+                ..bond_sale
+            };
+            let result = calculate_capped_amount(
+                &bond_sale,
+                0,
+                TokenAmount::new(20),
+                &VestingTerm::default(),
+            );
+            assert_eq!(result, TokenAmount::new(9));
+        }
+        // hard cap reached
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(100),
+                quote_raised: TokenAmount::new(100),
+                ..bond_sale
+            };
+            let result = calculate_capped_amount(
+                &bond_sale,
+                0,
+                TokenAmount::new(20),
+                &VestingTerm::default(),
+            );
+            assert_eq!(result, TokenAmount::new(0));
+        }
+    }
 }
@@ -2,10 +2,18 @@ use std::convert::TryInto;
 
 use anchor_lang::prelude::*;
 
-use crate::{errors::Result, structs::Decimal};
+use crate::{
+    errors::Result,
+    structs::{Decimal, DENOMINATOR},
+};
 
 use super::TokenAmount;
 
+// 1% of every delegated claim
+pub const MAX_DELEGATE_TIP: Decimal = Decimal {
+    v: DENOMINATOR / 100,
+};
+
 #[account(zero_copy)]
 #[repr(packed)]
 #[derive(Debug, Default)]
@@ -18,22 +26,47 @@ pub struct Bond {
     pub vesting_start: u64,
     pub vesting_end: u64,
     pub id: u128,
+    pub claimed_amount: TokenAmount,
+    pub delegate: Pubkey,
+    pub delegate_tip: Decimal,
+    pub rent_payer: Pubkey,
+    pub quote_amount: TokenAmount,
+    pub fee_amount: TokenAmount,
+    pub referral: Pubkey,
+    pub referral_amount: TokenAmount,
+    pub bond_fee: Decimal,
+    pub bond_fee_amount: TokenAmount,
+    pub sale_quote: Pubkey,
+    pub vesting_time: u64,
+    pub vesting_discount: Decimal,
 }
 
 impl Bond {
-    pub fn get_amount_to_claim(&mut self, current_time: u64) -> Result<TokenAmount> {
-        require!(self.last_claim < self.vesting_end, VestingEnded);
+    pub fn get_amount_to_claim(&self, current_time: u64) -> Result<TokenAmount> {
+        require!(!self.is_fully_claimed(), VestingEnded);
+
+        let vested_amount = match current_time < self.vesting_end {
+            true => {
+                let time_delta = current_time - self.vesting_start;
+                let duration = self.vesting_end - self.vesting_start;
+                let fraction = Decimal::from_integer(time_delta.try_into().unwrap())
+                    / Decimal::from_integer(duration.try_into().unwrap());
 
-        let time_delta = match current_time < self.vesting_end {
-            true => current_time - self.last_claim,
-            false => self.vesting_end - self.last_claim,
+                self.bond_amount.big_mul(fraction).to_token_floor()
+            }
+            false => self.bond_amount,
         };
-        let duration = self.vesting_end - self.vesting_start;
-        let fraction = Decimal::from_integer(time_delta.try_into().unwrap())
-            / Decimal::from_integer(duration.try_into().unwrap());
 
-        let amount_to_claim = self.bond_amount.big_mul(fraction).to_token_floor();
-        Ok(amount_to_claim)
+        Ok(vested_amount - self.claimed_amount)
+    }
+
+    // fee is charged on the total claimed so far, so it does not depend on how often the bond is claimed
+    pub fn get_bond_fee_due(&self) -> TokenAmount {
+        self.claimed_amount.big_mul(self.bond_fee).to_token_ceil() - self.bond_fee_amount
+    }
+
+    pub fn is_fully_claimed(&self) -> bool {
+        self.claimed_amount.v >= self.bond_amount.v
     }
 }
 
@@ -57,6 +90,7 @@ mod tests {
             bond.vesting_start = 0;
             bond.vesting_end = 10;
             bond.last_claim = 5;
+            bond.claimed_amount = TokenAmount::new(49);
             assert_eq!(bond.get_amount_to_claim(5).unwrap(), TokenAmount::new(0));
         }
         {
@@ -65,7 +99,8 @@ mod tests {
             bond.vesting_start = 0;
             bond.vesting_end = 10;
             bond.last_claim = 5;
-            assert_eq!(bond.get_amount_to_claim(10).unwrap(), TokenAmount::new(500));
+            bond.claimed_amount = TokenAmount::new(500);
+            assert_eq!(bond.get_amount_to_claim(10).unwrap(), TokenAmount::new(501));
         }
         {
             let mut bond = Bond::default();
@@ -73,7 +108,98 @@ mod tests {
             bond.vesting_start = 0;
             bond.vesting_end = 10;
             bond.last_claim = 5;
+            bond.claimed_amount = TokenAmount::new(50);
             assert_eq!(bond.get_amount_to_claim(15).unwrap(), TokenAmount::new(50));
         }
+        // partially claimed
+        {
+            let mut bond = Bond::default();
+            bond.bond_amount = TokenAmount::new(100);
+            bond.vesting_start = 0;
+            bond.vesting_end = 10;
+            bond.last_claim = 5;
+            bond.claimed_amount = TokenAmount::new(20);
+            assert_eq!(bond.get_amount_to_claim(5).unwrap(), TokenAmount::new(30));
+            assert_eq!(bond.get_amount_to_claim(10).unwrap(), TokenAmount::new(80));
+        }
+        // fully claimed
+        {
+            let mut bond = Bond::default();
+            bond.bond_amount = TokenAmount::new(100);
+            bond.vesting_start = 0;
+            bond.vesting_end = 10;
+            bond.last_claim = 10;
+            bond.claimed_amount = TokenAmount::new(100);
+            assert!(bond.get_amount_to_claim(15).is_err());
+        }
+        // claim exactly at vesting end
+        {
+            let mut bond = Bond::default();
+            bond.bond_amount = TokenAmount::new(100);
+            bond.vesting_start = 0;
+            bond.vesting_end = 10;
+            bond.last_claim = 5;
+            bond.claimed_amount = TokenAmount::new(50);
+            assert_eq!(bond.get_amount_to_claim(10).unwrap(), TokenAmount::new(50));
+        }
+    }
+
+    #[test]
+    fn test_is_fully_claimed() {
+        {
+            let bond = Bond {
+                bond_amount: TokenAmount::new(100),
+                claimed_amount: TokenAmount::new(99),
+                ..Default::default()
+            };
+            assert!(!bond.is_fully_claimed());
+        }
+        {
+            let bond = Bond {
+                bond_amount: TokenAmount::new(100),
+                claimed_amount: TokenAmount::new(100),
+                ..Default::default()
+            };
+            assert!(bond.is_fully_claimed());
+        }
+        // empty bond
+        {
+            let bond = Bond::default();
+            assert!(bond.is_fully_claimed());
+        }
+    }
+
+    #[test]
+    fn test_get_bond_fee_due() {
+        // no bond fee
+        {
+            let bond = Bond {
+                bond_amount: TokenAmount::new(100),
+                claimed_amount: TokenAmount::new(50),
+                ..Default::default()
+            };
+            assert_eq!(bond.get_bond_fee_due(), TokenAmount::new(0));
+        }
+        // rounding up
+        {
+            let bond = Bond {
+                bond_amount: TokenAmount::new(1000),
+                claimed_amount: TokenAmount::new(150),
+                bond_fee: Decimal::from_decimal(1, 2),
+                ..Default::default()
+            };
+            assert_eq!(bond.get_bond_fee_due(), TokenAmount::new(2));
+        }
+        // already charged part is skipped
+        {
+            let bond = Bond {
+                bond_amount: TokenAmount::new(1000),
+                claimed_amount: TokenAmount::new(1000),
+                bond_fee: Decimal::from_decimal(1, 2),
+                bond_fee_amount: TokenAmount::new(2),
+                ..Default::default()
+            };
+            assert_eq!(bond.get_bond_fee_due(), TokenAmount::new(8));
+        }
     }
 }
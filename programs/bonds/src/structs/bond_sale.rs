@@ -1,6 +1,28 @@
+use std::convert::TryInto;
+
 use anchor_lang::prelude::*;
 
-use super::{Decimal, TokenAmount};
+use super::{
+    Decimal, PriceFeed, ProceedsSplit, TokenAmount, VestingTerm, VolumeTier, MAX_PROCEEDS_SPLITS,
+    MAX_VESTING_TERMS, MAX_VOLUME_TIERS, REFERRAL_CLAIM_PERIOD,
+};
+
+pub const STATUS_ACTIVE: u8 = 0;
+pub const STATUS_SOLD_OUT: u8 = 1;
+
+pub const FEE_MODE_QUOTE: u8 = 0;
+pub const FEE_MODE_BOND: u8 = 1;
+pub const FEE_MODE_HYBRID: u8 = 2;
+
+pub const SUPPLY_MODE_TRANSFER: u8 = 0;
+pub const SUPPLY_MODE_MINT: u8 = 1;
+
+pub const PRICE_MODE_FIXED: u8 = 0;
+pub const PRICE_MODE_ORACLE: u8 = 1;
+pub const PRICE_MODE_DEBT: u8 = 2;
+
+pub const CEIL_MODE_CAP: u8 = 0;
+pub const CEIL_MODE_REJECT: u8 = 1;
 
 #[account(zero_copy)]
 #[repr(packed)]
@@ -26,12 +48,374 @@ pub struct BondSale {
     pub vesting_time: u64,
     pub next_bond: u128,
     pub id: u128,
+    pub soft_cap: TokenAmount,
+    pub quote_raised: TokenAmount,
+    pub hard_cap: TokenAmount,
+    pub status: u8,
+    pub proceeds_vesting_time: u64,
+    pub proceeds_destination: Pubkey,
+    pub proceeds_splits: [ProceedsSplit; MAX_PROCEEDS_SPLITS],
+    pub referral_fee: Decimal,
+    pub referral_amount: TokenAmount,
+    pub fee_mode: u8,
+    pub bond_fee: Decimal,
+    pub bond_fee_amount: TokenAmount,
+    pub supply_mode: u8,
+    pub minted_amount: TokenAmount,
+    pub sale_quote_raised: TokenAmount,
+    pub sale_quote_count: u8,
+    pub price_mode: u8,
+    pub oracle: Pubkey,
+    pub oracle_discount: Decimal,
+    pub oracle_max_staleness: u64,
+    pub oracle_max_confidence: Decimal,
+    pub control_variable: Decimal,
+    pub total_debt: TokenAmount,
+    pub last_decay: u64,
+    pub max_payout: TokenAmount,
+    pub max_debt: TokenAmount,
+    pub adjustment_target: Decimal,
+    pub adjustment_rate: Decimal,
+    pub adjustment_buffer: u64,
+    pub last_adjustment: u64,
+    pub velocity_gain: Decimal,
+    pub min_velocity: Decimal,
+    pub max_velocity: Decimal,
+    pub ceil_mode: u8,
+    pub price_cumulative: u128,
+    pub last_observation: u64,
+    pub last_observed_price: Decimal,
+    pub volume_tiers: [VolumeTier; MAX_VOLUME_TIERS],
+    pub vesting_terms: [VestingTerm; MAX_VESTING_TERMS],
+    pub oracle_program: Pubkey,
 }
 
 impl BondSale {
     pub fn calculate_ceil_price(&self) -> Decimal {
         (Decimal::one() + self.up_bound) * self.floor_price
     }
+
+    // purchases that would end above the ceiling fail in reject mode and are capped otherwise
+    pub fn is_ceil_price_rejected(&self, end_price: Decimal) -> bool {
+        self.ceil_mode == CEIL_MODE_REJECT
+            && self.price_mode != PRICE_MODE_DEBT
+            && end_price > self.calculate_ceil_price()
+    }
+
+    // native sales hold wSOL in the quote vault
+    pub fn is_native(&self) -> bool {
+        self.token_quote == spl_token::native_mint::ID
+    }
+
+    // bonds are minted at claim while the program holds the mint authority
+    pub fn is_mint_on_demand(&self) -> bool {
+        self.supply_mode == SUPPLY_MODE_MINT
+    }
+
+    // sold bonds that are not minted yet
+    pub fn get_unminted_amount(&self) -> TokenAmount {
+        self.supply - self.remaining_amount - self.minted_amount
+    }
+
+    // floor at a discount to the feed price, which has to be recent and precise enough
+    pub fn get_oracle_floor_price(
+        &self,
+        feed: &PriceFeed,
+        current_time: u64,
+    ) -> std::result::Result<Decimal, ProgramError> {
+        require!(!feed.price.is_zero(), InvalidOracle);
+        require!(
+            current_time <= feed.publish_time.saturating_add(self.oracle_max_staleness),
+            StaleOracle
+        );
+        require!(
+            feed.confidence <= feed.price * self.oracle_max_confidence,
+            OracleConfidenceExceeded
+        );
+
+        Ok(feed.price * (Decimal::one() - self.oracle_discount))
+    }
+
+    // oracle priced sales move their floor with the feed before every trade
+    pub fn refresh_floor_price(
+        &mut self,
+        oracle: &AccountInfo,
+        current_time: u64,
+    ) -> ProgramResult {
+        if self.price_mode == PRICE_MODE_ORACLE {
+            let feed = PriceFeed::load(oracle, &self.oracle_program)?;
+            self.floor_price = self.get_oracle_floor_price(&feed, current_time)?;
+        }
+        Ok(())
+    }
+
+    // outstanding debt decays linearly over the vesting time
+    pub fn get_debt(&self, current_time: u64) -> TokenAmount {
+        let elapsed = current_time - self.last_decay;
+        if elapsed >= self.vesting_time {
+            return TokenAmount::new(0);
+        }
+
+        let decay = (self.total_debt.v as u128) * (elapsed as u128) / (self.vesting_time as u128);
+        self.total_debt - TokenAmount::new(decay.try_into().unwrap())
+    }
+
+    // adjustments that are due, each one a full adjustment_buffer after the previous
+    pub fn get_adjustment_steps(&self, current_time: u64) -> u64 {
+        if self.adjustment_buffer == 0 {
+            return 0;
+        }
+        (current_time - self.last_adjustment) / self.adjustment_buffer
+    }
+
+    // every due adjustment moves the control variable by adjustment_rate towards the target
+    pub fn get_control_variable(&self, current_time: u64) -> Decimal {
+        let steps = self.get_adjustment_steps(current_time);
+        let change = self.adjustment_rate * Decimal::from_integer(steps.into());
+
+        match { self.control_variable } < { self.adjustment_target } {
+            true => match self.adjustment_target - self.control_variable <= change {
+                true => self.adjustment_target,
+                false => self.control_variable + change,
+            },
+            false => match self.control_variable - self.adjustment_target <= change {
+                true => self.adjustment_target,
+                false => self.control_variable - change,
+            },
+        }
+    }
+
+    // price = control variable * debt ratio, never below the floor
+    pub fn get_debt_price(&self, current_time: u64) -> Decimal {
+        let debt_ratio = self.get_debt(current_time).percent(self.supply);
+        let price = self.get_control_variable(current_time) * debt_ratio;
+
+        match price < { self.floor_price } {
+            true => self.floor_price,
+            false => price,
+        }
+    }
+
+    pub fn check_debt_limits(&self, buy_amount: TokenAmount, current_time: u64) -> ProgramResult {
+        if self.price_mode != PRICE_MODE_DEBT {
+            return Ok(());
+        }
+
+        require!(
+            self.max_payout.is_zero() || buy_amount <= self.max_payout,
+            MaxPayoutExceeded
+        );
+        require!(
+            self.max_debt.is_zero() || self.get_debt(current_time) + buy_amount <= self.max_debt,
+            MaxDebtExceeded
+        );
+        Ok(())
+    }
+
+    // decays the debt, applies due adjustments and records the new debt
+    pub fn add_debt(&mut self, buy_amount: TokenAmount, current_time: u64) {
+        let steps = self.get_adjustment_steps(current_time);
+
+        self.control_variable = self.get_control_variable(current_time);
+        self.last_adjustment += steps * self.adjustment_buffer;
+        self.total_debt = self.get_debt(current_time) + buy_amount;
+        self.last_decay = current_time;
+    }
+
+    // fraction of the sale time that has passed
+    pub fn get_time_fraction(&self, current_time: u64) -> Decimal {
+        let elapsed = current_time.max(self.start_time) - self.start_time;
+        let sale_time = self.end_time - self.start_time;
+        if elapsed >= sale_time {
+            return Decimal::one();
+        }
+        Decimal::from_integer(elapsed.into()) / Decimal::from_integer(sale_time.into())
+    }
+
+    // selling ahead of schedule slows the price decay down, falling behind speeds it up
+    pub fn update_velocity(&mut self, current_time: u64) {
+        if self.velocity_gain.is_zero() {
+            return;
+        }
+
+        let time_fraction = self.get_time_fraction(current_time);
+        let sold_fraction = (self.supply - self.remaining_amount).percent(self.supply);
+
+        let velocity = match time_fraction < sold_fraction {
+            true => {
+                let change = self.velocity_gain * (sold_fraction - time_fraction);
+                match { self.velocity } < { self.min_velocity + change } {
+                    true => self.min_velocity,
+                    false => self.velocity - change,
+                }
+            }
+            false => {
+                let velocity = self.velocity + self.velocity_gain * (time_fraction - sold_fraction);
+                match velocity > { self.max_velocity } {
+                    true => self.max_velocity,
+                    false => velocity,
+                }
+            }
+        };
+        self.velocity = velocity;
+    }
+
+    // price integrated over time, linear between two observations and allowed to wrap
+    pub fn accumulate_price(&mut self, price: Decimal, current_time: u64) {
+        let elapsed = current_time.max(self.last_observation) - self.last_observation;
+        let (previous, current) = (self.last_observed_price.v, price.v);
+        let average = previous / 2 + current / 2 + (previous % 2 + current % 2) / 2;
+
+        self.price_cumulative =
+            { self.price_cumulative }.wrapping_add(average.wrapping_mul(elapsed as u128));
+        self.last_observation = current_time.max(self.last_observation);
+        self.last_observed_price = price;
+    }
+
+    // discount of the highest tier the purchase reaches, tiers are sorted by min_amount
+    pub fn get_volume_discount(&self, buy_amount: TokenAmount) -> Decimal {
+        { self.volume_tiers }
+            .iter()
+            .filter(|tier| tier.is_active() && buy_amount >= tier.min_amount)
+            .last()
+            .map_or(Decimal::new(0), |tier| tier.discount)
+    }
+
+    pub fn apply_volume_discount(&self, price: Decimal, buy_amount: TokenAmount) -> Decimal {
+        price * (Decimal::one() - self.get_volume_discount(buy_amount))
+    }
+
+    // one of the offered terms, or the sale vesting time at the curve price when none is chosen
+    pub fn get_vesting_term(
+        &self,
+        vesting_term: Option<u8>,
+    ) -> std::result::Result<VestingTerm, ProgramError> {
+        let index = match vesting_term {
+            Some(index) => index as usize,
+            None => {
+                return Ok(VestingTerm {
+                    vesting_time: self.vesting_time,
+                    discount: Decimal::new(0),
+                })
+            }
+        };
+
+        let term = { self.vesting_terms }
+            .get(index)
+            .copied()
+            .filter(|term| term.is_active())
+            .ok_or(crate::ErrorCode::InvalidVestingTerm)?;
+        Ok(term)
+    }
+
+    // only the main quote token counts, sale quotes are accepted once the soft cap is reached
+    // so they are never refunded, while the hard cap limits everything raised
+    pub fn is_soft_cap_reached(&self) -> bool {
+        self.quote_raised.v >= self.soft_cap.v
+    }
+
+    pub fn is_soft_cap_failed(&self, current_time: u64) -> bool {
+        !self.is_soft_cap_reached() && current_time >= self.end_time
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        self.status == STATUS_SOLD_OUT || self.remaining_amount.is_zero()
+    }
+
+    // raised in the pricing unit over the main quote token and every sale quote
+    pub fn get_total_raised(&self) -> TokenAmount {
+        self.quote_raised + self.sale_quote_raised
+    }
+
+    pub fn is_hard_cap_reached(&self) -> bool {
+        !self.hard_cap.is_zero() && self.get_total_raised().v >= self.hard_cap.v
+    }
+
+    // fee taken from the quote at purchase
+    pub fn get_quote_fee(&self) -> Decimal {
+        match self.fee_mode {
+            FEE_MODE_BOND => Decimal::new(0),
+            _ => self.fee,
+        }
+    }
+
+    // fee taken from the bond tokens at claim
+    pub fn get_bond_fee(&self) -> Decimal {
+        match self.fee_mode {
+            FEE_MODE_QUOTE => Decimal::new(0),
+            _ => self.bond_fee,
+        }
+    }
+
+    pub fn is_referral_claim_expired(&self, current_time: u64) -> bool {
+        current_time >= self.end_time.saturating_add(REFERRAL_CLAIM_PERIOD)
+    }
+
+    // portion of the fee that goes to the referrer
+    pub fn calculate_referral_amount(&self, fee: TokenAmount) -> TokenAmount {
+        fee.big_mul(self.referral_fee).to_token_floor()
+    }
+
+    // proceeds vest linearly after the sale ends
+    pub fn get_claimable_quote(&self, current_time: u64) -> TokenAmount {
+        self.get_vested_quote(self.quote_raised, self.quote_amount, current_time)
+    }
+
+    // same schedule for any quote token, from what it raised and what is still unclaimed
+    pub fn get_vested_quote(
+        &self,
+        quote_raised: TokenAmount,
+        quote_amount: TokenAmount,
+        current_time: u64,
+    ) -> TokenAmount {
+        let vesting_end = self.end_time + self.proceeds_vesting_time;
+        if self.proceeds_vesting_time == 0 || current_time >= vesting_end {
+            return quote_amount;
+        }
+
+        let vested_amount = match current_time > self.end_time {
+            true => {
+                let fraction =
+                    Decimal::from_integer((current_time - self.end_time).try_into().unwrap())
+                        / Decimal::from_integer(self.proceeds_vesting_time.try_into().unwrap());
+
+                quote_raised.big_mul(fraction).to_token_floor()
+            }
+            false => TokenAmount::new(0),
+        };
+        let claimed_amount = quote_raised - quote_amount;
+
+        match vested_amount.v > claimed_amount.v {
+            true => vested_amount - claimed_amount,
+            false => TokenAmount::new(0),
+        }
+    }
+
+    // splits are paid first, the proceeds destination gets the rest including rounding
+    pub fn distribute_quote(
+        &mut self,
+        released: TokenAmount,
+    ) -> ([TokenAmount; MAX_PROCEEDS_SPLITS], TokenAmount) {
+        let total_released = self.quote_raised - self.quote_amount + released;
+        let mut splits = { self.proceeds_splits };
+        let mut split_amounts = [TokenAmount::new(0); MAX_PROCEEDS_SPLITS];
+        let mut destination_amount = released;
+
+        for (split, split_amount) in splits.iter_mut().zip(split_amounts.iter_mut()) {
+            if !split.is_active() {
+                continue;
+            }
+            let undistributed = split.get_undistributed(total_released);
+            *split_amount = TokenAmount::new(undistributed.v.min(destination_amount.v));
+
+            split.distributed = { split.distributed } + *split_amount;
+            destination_amount -= *split_amount;
+        }
+
+        self.proceeds_splits = splits;
+        self.quote_amount -= released;
+        (split_amounts, destination_amount)
+    }
 }
 
 #[cfg(test)]
@@ -47,4 +431,736 @@ mod tests {
             assert_eq!(bond_sale.calculate_ceil_price(), Decimal::from_integer(3));
         }
     }
+
+    #[test]
+    fn test_is_ceil_price_rejected() {
+        let bond_sale = BondSale {
+            floor_price: Decimal::from_integer(2),
+            up_bound: Decimal::from_decimal(50, 2),
+            ceil_mode: CEIL_MODE_REJECT,
+            ..Default::default()
+        };
+        assert!(!bond_sale.is_ceil_price_rejected(Decimal::from_integer(3)));
+        assert!(bond_sale.is_ceil_price_rejected(Decimal::new(Decimal::from_integer(3).v + 1)));
+        // capped instead
+        {
+            let bond_sale = BondSale {
+                ceil_mode: CEIL_MODE_CAP,
+                ..bond_sale
+            };
+            assert!(!bond_sale.is_ceil_price_rejected(Decimal::from_integer(4)));
+        }
+        // debt pricing has no ceiling
+        {
+            let bond_sale = BondSale {
+                price_mode: PRICE_MODE_DEBT,
+                ..bond_sale
+            };
+            assert!(!bond_sale.is_ceil_price_rejected(Decimal::from_integer(4)));
+        }
+    }
+
+    #[test]
+    fn test_soft_cap() {
+        // no soft cap
+        {
+            let bond_sale = BondSale {
+                end_time: 10,
+                ..Default::default()
+            };
+            assert!(bond_sale.is_soft_cap_reached());
+            assert!(!bond_sale.is_soft_cap_failed(10));
+        }
+        // not reached yet
+        {
+            let bond_sale = BondSale {
+                soft_cap: TokenAmount::new(100),
+                quote_raised: TokenAmount::new(99),
+                end_time: 10,
+                ..Default::default()
+            };
+            assert!(!bond_sale.is_soft_cap_reached());
+            assert!(!bond_sale.is_soft_cap_failed(9));
+            assert!(bond_sale.is_soft_cap_failed(10));
+        }
+        // reached
+        {
+            let bond_sale = BondSale {
+                soft_cap: TokenAmount::new(100),
+                quote_raised: TokenAmount::new(100),
+                end_time: 10,
+                ..Default::default()
+            };
+            assert!(bond_sale.is_soft_cap_reached());
+            assert!(!bond_sale.is_soft_cap_failed(10));
+        }
+    }
+
+    #[test]
+    fn test_soft_cap_with_sale_quotes() {
+        // sale quotes count toward the hard cap only
+        let bond_sale = BondSale {
+            soft_cap: TokenAmount::new(100),
+            hard_cap: TokenAmount::new(100),
+            quote_raised: TokenAmount::new(60),
+            sale_quote_raised: TokenAmount::new(40),
+            end_time: 10,
+            ..Default::default()
+        };
+        assert!(!bond_sale.is_soft_cap_reached());
+        assert!(bond_sale.is_soft_cap_failed(10));
+        assert!(bond_sale.is_hard_cap_reached());
+    }
+
+    #[test]
+    fn test_is_sold_out() {
+        {
+            let bond_sale = BondSale {
+                remaining_amount: TokenAmount::new(1),
+                ..Default::default()
+            };
+            assert!(!bond_sale.is_sold_out());
+        }
+        {
+            let bond_sale = BondSale {
+                remaining_amount: TokenAmount::new(0),
+                ..Default::default()
+            };
+            assert!(bond_sale.is_sold_out());
+        }
+        {
+            let bond_sale = BondSale {
+                remaining_amount: TokenAmount::new(1),
+                status: STATUS_SOLD_OUT,
+                ..Default::default()
+            };
+            assert!(bond_sale.is_sold_out());
+        }
+    }
+
+    #[test]
+    fn test_is_hard_cap_reached() {
+        // no hard cap
+        {
+            let bond_sale = BondSale {
+                quote_raised: TokenAmount::new(100),
+                ..Default::default()
+            };
+            assert!(!bond_sale.is_hard_cap_reached());
+        }
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(100),
+                quote_raised: TokenAmount::new(99),
+                ..Default::default()
+            };
+            assert!(!bond_sale.is_hard_cap_reached());
+        }
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(100),
+                quote_raised: TokenAmount::new(100),
+                ..Default::default()
+            };
+            assert!(bond_sale.is_hard_cap_reached());
+        }
+        // sale quotes count towards the hard cap
+        {
+            let bond_sale = BondSale {
+                hard_cap: TokenAmount::new(100),
+                quote_raised: TokenAmount::new(60),
+                sale_quote_raised: TokenAmount::new(40),
+                ..Default::default()
+            };
+            assert!(bond_sale.is_hard_cap_reached());
+        }
+    }
+
+    #[test]
+    fn test_get_oracle_floor_price() {
+        let bond_sale = BondSale {
+            price_mode: PRICE_MODE_ORACLE,
+            oracle_discount: Decimal::from_decimal(10, 2),
+            oracle_max_staleness: 60,
+            oracle_max_confidence: Decimal::from_decimal(1, 2),
+            ..Default::default()
+        };
+        let feed = PriceFeed {
+            price: Decimal::from_integer(2),
+            confidence: Decimal::from_decimal(2, 2),
+            publish_time: 100,
+        };
+        // discounted
+        {
+            let floor_price = bond_sale.get_oracle_floor_price(&feed, 160).unwrap();
+            assert_eq!(floor_price, Decimal::from_decimal(180, 2));
+        }
+        // stale
+        {
+            assert!(bond_sale.get_oracle_floor_price(&feed, 161).is_err());
+        }
+        // staleness does not overflow
+        {
+            let bond_sale = BondSale {
+                oracle_max_staleness: u64::MAX,
+                ..bond_sale
+            };
+            assert!(bond_sale.get_oracle_floor_price(&feed, u64::MAX).is_ok());
+        }
+        // confidence too wide
+        {
+            let feed = PriceFeed {
+                confidence: Decimal::from_decimal(3, 2),
+                ..feed
+            };
+            assert!(bond_sale.get_oracle_floor_price(&feed, 100).is_err());
+        }
+        // no price
+        {
+            let feed = PriceFeed {
+                price: Decimal::new(0),
+                ..feed
+            };
+            assert!(bond_sale.get_oracle_floor_price(&feed, 100).is_err());
+        }
+    }
+
+    #[test]
+    fn test_get_debt() {
+        let bond_sale = BondSale {
+            total_debt: TokenAmount::new(1000),
+            last_decay: 100,
+            vesting_time: 50,
+            ..Default::default()
+        };
+        assert_eq!(bond_sale.get_debt(100), TokenAmount::new(1000));
+        assert_eq!(bond_sale.get_debt(110), TokenAmount::new(800));
+        assert_eq!(bond_sale.get_debt(149), TokenAmount::new(20));
+        assert_eq!(bond_sale.get_debt(150), TokenAmount::new(0));
+        assert_eq!(bond_sale.get_debt(1000), TokenAmount::new(0));
+    }
+
+    #[test]
+    fn test_get_control_variable() {
+        let bond_sale = BondSale {
+            control_variable: Decimal::from_integer(10),
+            adjustment_target: Decimal::from_integer(12),
+            adjustment_rate: Decimal::from_decimal(5, 1),
+            adjustment_buffer: 10,
+            last_adjustment: 100,
+            ..Default::default()
+        };
+        // up to the target
+        {
+            assert_eq!(
+                bond_sale.get_control_variable(109),
+                Decimal::from_integer(10)
+            );
+            assert_eq!(
+                bond_sale.get_control_variable(110),
+                Decimal::from_decimal(105, 1)
+            );
+            assert_eq!(
+                bond_sale.get_control_variable(135),
+                Decimal::from_decimal(115, 1)
+            );
+            assert_eq!(
+                bond_sale.get_control_variable(1000),
+                Decimal::from_integer(12)
+            );
+        }
+        // down to the target
+        {
+            let bond_sale = BondSale {
+                adjustment_target: Decimal::from_integer(9),
+                ..bond_sale
+            };
+            assert_eq!(
+                bond_sale.get_control_variable(110),
+                Decimal::from_decimal(95, 1)
+            );
+            assert_eq!(
+                bond_sale.get_control_variable(1000),
+                Decimal::from_integer(9)
+            );
+        }
+        // no adjustment
+        {
+            let bond_sale = BondSale {
+                adjustment_buffer: 0,
+                ..bond_sale
+            };
+            assert_eq!(
+                bond_sale.get_control_variable(1000),
+                Decimal::from_integer(10)
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_debt_price() {
+        let bond_sale = BondSale {
+            price_mode: PRICE_MODE_DEBT,
+            floor_price: Decimal::from_decimal(5, 1),
+            control_variable: Decimal::from_integer(4),
+            supply: TokenAmount::new(1000),
+            total_debt: TokenAmount::new(250),
+            last_decay: 100,
+            vesting_time: 100,
+            ..Default::default()
+        };
+        assert_eq!(bond_sale.get_debt_price(100), Decimal::from_integer(1));
+        // floor once the debt decays
+        assert_eq!(bond_sale.get_debt_price(180), Decimal::from_decimal(5, 1));
+    }
+
+    #[test]
+    fn test_check_debt_limits() {
+        let bond_sale = BondSale {
+            price_mode: PRICE_MODE_DEBT,
+            total_debt: TokenAmount::new(100),
+            last_decay: 100,
+            vesting_time: 100,
+            max_payout: TokenAmount::new(50),
+            max_debt: TokenAmount::new(140),
+            ..Default::default()
+        };
+        assert!(bond_sale
+            .check_debt_limits(TokenAmount::new(40), 100)
+            .is_ok());
+        assert!(bond_sale
+            .check_debt_limits(TokenAmount::new(41), 100)
+            .is_err());
+        assert!(bond_sale
+            .check_debt_limits(TokenAmount::new(50), 110)
+            .is_ok());
+        assert!(bond_sale
+            .check_debt_limits(TokenAmount::new(51), 150)
+            .is_err());
+        // no limits
+        {
+            let bond_sale = BondSale {
+                max_payout: TokenAmount::new(0),
+                max_debt: TokenAmount::new(0),
+                ..bond_sale
+            };
+            assert!(bond_sale
+                .check_debt_limits(TokenAmount::new(1000), 100)
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_add_debt() {
+        let mut bond_sale = BondSale {
+            price_mode: PRICE_MODE_DEBT,
+            control_variable: Decimal::from_integer(10),
+            adjustment_target: Decimal::from_integer(12),
+            adjustment_rate: Decimal::from_integer(1),
+            adjustment_buffer: 10,
+            last_adjustment: 100,
+            total_debt: TokenAmount::new(100),
+            last_decay: 100,
+            vesting_time: 100,
+            ..Default::default()
+        };
+        bond_sale.add_debt(TokenAmount::new(30), 125);
+
+        assert_eq!({ bond_sale.control_variable }, Decimal::from_integer(12));
+        assert_eq!({ bond_sale.last_adjustment }, 120);
+        assert_eq!({ bond_sale.total_debt }, TokenAmount::new(105));
+        assert_eq!({ bond_sale.last_decay }, 125);
+    }
+
+    #[test]
+    fn test_update_velocity() {
+        let bond_sale = BondSale {
+            velocity: Decimal::one(),
+            velocity_gain: Decimal::from_integer(2),
+            min_velocity: Decimal::from_decimal(5, 1),
+            max_velocity: Decimal::from_integer(2),
+            supply: TokenAmount::new(1000),
+            remaining_amount: TokenAmount::new(800),
+            start_time: 100,
+            end_time: 200,
+            ..Default::default()
+        };
+        // ahead of schedule
+        {
+            let mut bond_sale = bond_sale;
+            bond_sale.update_velocity(110);
+            assert_eq!({ bond_sale.velocity }, Decimal::from_decimal(8, 1));
+        }
+        // on schedule
+        {
+            let mut bond_sale = bond_sale;
+            bond_sale.update_velocity(120);
+            assert_eq!({ bond_sale.velocity }, Decimal::one());
+        }
+        // behind schedule
+        {
+            let mut bond_sale = bond_sale;
+            bond_sale.update_velocity(150);
+            assert_eq!({ bond_sale.velocity }, Decimal::from_decimal(16, 1));
+        }
+        // bounded
+        {
+            let mut bond_sale = bond_sale;
+            bond_sale.update_velocity(100);
+            assert_eq!({ bond_sale.velocity }, Decimal::from_decimal(6, 1));
+            bond_sale.update_velocity(100);
+            assert_eq!({ bond_sale.velocity }, Decimal::from_decimal(5, 1));
+            bond_sale.update_velocity(300);
+            assert_eq!({ bond_sale.velocity }, Decimal::from_integer(2));
+        }
+        // disabled
+        {
+            let mut bond_sale = BondSale {
+                velocity_gain: Decimal::new(0),
+                ..bond_sale
+            };
+            bond_sale.update_velocity(150);
+            assert_eq!({ bond_sale.velocity }, Decimal::one());
+        }
+    }
+
+    #[test]
+    fn test_accumulate_price() {
+        let mut bond_sale = BondSale {
+            last_observation: 100,
+            last_observed_price: Decimal::from_integer(2),
+            ..Default::default()
+        };
+        // price moved from 2 to 4, averaging 3 over 10 seconds
+        bond_sale.accumulate_price(Decimal::from_integer(4), 110);
+        assert_eq!({ bond_sale.price_cumulative }, Decimal::from_integer(30).v);
+        assert_eq!({ bond_sale.last_observation }, 110);
+        assert_eq!({ bond_sale.last_observed_price }, Decimal::from_integer(4));
+
+        // no time passed
+        bond_sale.accumulate_price(Decimal::from_integer(6), 110);
+        assert_eq!({ bond_sale.price_cumulative }, Decimal::from_integer(30).v);
+        assert_eq!({ bond_sale.last_observed_price }, Decimal::from_integer(6));
+
+        // wraps around
+        {
+            let mut bond_sale = BondSale {
+                price_cumulative: u128::MAX,
+                ..bond_sale
+            };
+            bond_sale.accumulate_price(Decimal::new(6), 111);
+            assert_eq!(
+                { bond_sale.price_cumulative },
+                Decimal::from_integer(3).v + 2
+            );
+        }
+        // extreme prices do not overflow the average or the product
+        {
+            let mut bond_sale = BondSale {
+                price_cumulative: 0,
+                last_observed_price: Decimal::new(u128::MAX),
+                ..bond_sale
+            };
+            bond_sale.accumulate_price(Decimal::new(u128::MAX), 112);
+            assert_eq!({ bond_sale.price_cumulative }, u128::MAX - 1);
+        }
+    }
+
+    #[test]
+    fn test_get_volume_discount() {
+        let mut bond_sale = BondSale::default();
+        assert_eq!(
+            bond_sale.get_volume_discount(TokenAmount::new(1_000_000)),
+            Decimal::new(0)
+        );
+
+        bond_sale.volume_tiers[0] = VolumeTier {
+            min_amount: TokenAmount::new(10_000),
+            discount: Decimal::from_decimal(2, 2),
+        };
+        bond_sale.volume_tiers[1] = VolumeTier {
+            min_amount: TokenAmount::new(100_000),
+            discount: Decimal::from_decimal(5, 2),
+        };
+        assert_eq!(
+            bond_sale.get_volume_discount(TokenAmount::new(9_999)),
+            Decimal::new(0)
+        );
+        assert_eq!(
+            bond_sale.get_volume_discount(TokenAmount::new(10_000)),
+            Decimal::from_decimal(2, 2)
+        );
+        assert_eq!(
+            bond_sale.get_volume_discount(TokenAmount::new(99_999)),
+            Decimal::from_decimal(2, 2)
+        );
+        assert_eq!(
+            bond_sale.get_volume_discount(TokenAmount::new(100_000)),
+            Decimal::from_decimal(5, 2)
+        );
+        assert_eq!(
+            bond_sale.apply_volume_discount(Decimal::from_integer(2), TokenAmount::new(100_000)),
+            Decimal::from_decimal(190, 2)
+        );
+    }
+
+    #[test]
+    fn test_get_vesting_term() {
+        let mut bond_sale = BondSale {
+            vesting_time: 100,
+            ..Default::default()
+        };
+        bond_sale.vesting_terms[0] = VestingTerm {
+            vesting_time: 7 * 86_400,
+            discount: Decimal::from_decimal(1, 2),
+        };
+        bond_sale.vesting_terms[1] = VestingTerm {
+            vesting_time: 90 * 86_400,
+            discount: Decimal::from_decimal(5, 2),
+        };
+
+        assert_eq!(
+            bond_sale.get_vesting_term(None).unwrap(),
+            VestingTerm {
+                vesting_time: 100,
+                discount: Decimal::new(0)
+            }
+        );
+        assert_eq!(
+            bond_sale.get_vesting_term(Some(1)).unwrap(),
+            VestingTerm {
+                vesting_time: 90 * 86_400,
+                discount: Decimal::from_decimal(5, 2)
+            }
+        );
+        assert_eq!(
+            bond_sale
+                .get_vesting_term(Some(1))
+                .unwrap()
+                .apply_discount(Decimal::from_integer(2)),
+            Decimal::from_decimal(190, 2)
+        );
+        // not offered
+        assert!(bond_sale.get_vesting_term(Some(2)).is_err());
+        assert!(bond_sale.get_vesting_term(Some(4)).is_err());
+    }
+
+    #[test]
+    fn test_get_claimable_quote() {
+        // no vesting
+        {
+            let bond_sale = BondSale {
+                quote_raised: TokenAmount::new(100),
+                quote_amount: TokenAmount::new(100),
+                end_time: 10,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_claimable_quote(0), TokenAmount::new(100));
+        }
+        // locked until the sale ends
+        {
+            let bond_sale = BondSale {
+                quote_raised: TokenAmount::new(100),
+                quote_amount: TokenAmount::new(100),
+                end_time: 10,
+                proceeds_vesting_time: 10,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_claimable_quote(5), TokenAmount::new(0));
+            assert_eq!(bond_sale.get_claimable_quote(10), TokenAmount::new(0));
+            assert_eq!(bond_sale.get_claimable_quote(15), TokenAmount::new(50));
+            assert_eq!(bond_sale.get_claimable_quote(20), TokenAmount::new(100));
+            assert_eq!(bond_sale.get_claimable_quote(30), TokenAmount::new(100));
+        }
+        // partially claimed
+        {
+            let bond_sale = BondSale {
+                quote_raised: TokenAmount::new(100),
+                quote_amount: TokenAmount::new(75),
+                end_time: 10,
+                proceeds_vesting_time: 4,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_claimable_quote(11), TokenAmount::new(0));
+            assert_eq!(bond_sale.get_claimable_quote(12), TokenAmount::new(25));
+            assert_eq!(bond_sale.get_claimable_quote(14), TokenAmount::new(75));
+        }
+    }
+
+    #[test]
+    fn test_distribute_quote() {
+        // no splits
+        {
+            let mut bond_sale = BondSale {
+                quote_raised: TokenAmount::new(100),
+                quote_amount: TokenAmount::new(100),
+                ..Default::default()
+            };
+            let (split_amounts, destination_amount) =
+                bond_sale.distribute_quote(TokenAmount::new(100));
+            assert_eq!(split_amounts, [TokenAmount::new(0); MAX_PROCEEDS_SPLITS]);
+            assert_eq!(destination_amount, TokenAmount::new(100));
+            assert_eq!({ bond_sale.quote_amount }, TokenAmount::new(0));
+        }
+        // partial releases
+        {
+            let mut bond_sale = BondSale {
+                quote_raised: TokenAmount::new(101),
+                quote_amount: TokenAmount::new(101),
+                ..Default::default()
+            };
+            bond_sale.proceeds_splits[0].bps = 5000;
+            bond_sale.proceeds_splits[1].bps = 2500;
+
+            let (split_amounts, destination_amount) =
+                bond_sale.distribute_quote(TokenAmount::new(50));
+            assert_eq!(split_amounts[0], TokenAmount::new(25));
+            assert_eq!(split_amounts[1], TokenAmount::new(12));
+            assert_eq!(destination_amount, TokenAmount::new(13));
+
+            let (split_amounts, destination_amount) =
+                bond_sale.distribute_quote(TokenAmount::new(51));
+            assert_eq!(split_amounts[0], TokenAmount::new(25));
+            assert_eq!(split_amounts[1], TokenAmount::new(13));
+            assert_eq!(destination_amount, TokenAmount::new(13));
+
+            let splits = { bond_sale.proceeds_splits };
+            assert_eq!({ splits[0].distributed }, TokenAmount::new(50));
+            assert_eq!({ splits[1].distributed }, TokenAmount::new(25));
+            assert_eq!({ bond_sale.quote_amount }, TokenAmount::new(0));
+        }
+        // splits never take more than released
+        {
+            let mut bond_sale = BondSale {
+                quote_raised: TokenAmount::new(2),
+                quote_amount: TokenAmount::new(1),
+                ..Default::default()
+            };
+            bond_sale.proceeds_splits[0].bps = 5000;
+            bond_sale.proceeds_splits[1].bps = 5000;
+
+            let (split_amounts, destination_amount) =
+                bond_sale.distribute_quote(TokenAmount::new(1));
+            assert_eq!(split_amounts[0], TokenAmount::new(1));
+            assert_eq!(split_amounts[1], TokenAmount::new(0));
+            assert_eq!(destination_amount, TokenAmount::new(0));
+        }
+    }
+
+    #[test]
+    fn test_is_referral_claim_expired() {
+        let bond_sale = BondSale {
+            end_time: 100,
+            ..Default::default()
+        };
+        assert!(!bond_sale.is_referral_claim_expired(100));
+        assert!(!bond_sale.is_referral_claim_expired(99 + REFERRAL_CLAIM_PERIOD));
+        assert!(bond_sale.is_referral_claim_expired(100 + REFERRAL_CLAIM_PERIOD));
+    }
+
+    #[test]
+    fn test_calculate_referral_amount() {
+        // no referral fee
+        {
+            let bond_sale = BondSale::default();
+            assert_eq!(
+                bond_sale.calculate_referral_amount(TokenAmount::new(100)),
+                TokenAmount::new(0)
+            );
+        }
+        // rounding down
+        {
+            let bond_sale = BondSale {
+                referral_fee: Decimal::from_decimal(25, 2),
+                ..Default::default()
+            };
+            assert_eq!(
+                bond_sale.calculate_referral_amount(TokenAmount::new(100)),
+                TokenAmount::new(25)
+            );
+            assert_eq!(
+                bond_sale.calculate_referral_amount(TokenAmount::new(3)),
+                TokenAmount::new(0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_fee_mode() {
+        let fee = Decimal::from_decimal(1, 2);
+        let bond_fee = Decimal::from_decimal(2, 2);
+        // quote side
+        {
+            let bond_sale = BondSale {
+                fee,
+                bond_fee,
+                fee_mode: FEE_MODE_QUOTE,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_quote_fee(), fee);
+            assert_eq!(bond_sale.get_bond_fee(), Decimal::new(0));
+        }
+        // bond side
+        {
+            let bond_sale = BondSale {
+                fee,
+                bond_fee,
+                fee_mode: FEE_MODE_BOND,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_quote_fee(), Decimal::new(0));
+            assert_eq!(bond_sale.get_bond_fee(), bond_fee);
+        }
+        // hybrid
+        {
+            let bond_sale = BondSale {
+                fee,
+                bond_fee,
+                fee_mode: FEE_MODE_HYBRID,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_quote_fee(), fee);
+            assert_eq!(bond_sale.get_bond_fee(), bond_fee);
+        }
+    }
+
+    #[test]
+    fn test_is_native() {
+        {
+            let bond_sale = BondSale::default();
+            assert!(!bond_sale.is_native());
+        }
+        {
+            let bond_sale = BondSale {
+                token_quote: spl_token::native_mint::ID,
+                ..Default::default()
+            };
+            assert!(bond_sale.is_native());
+        }
+    }
+
+    #[test]
+    fn test_get_unminted_amount() {
+        {
+            let bond_sale = BondSale {
+                supply: TokenAmount::new(1000),
+                remaining_amount: TokenAmount::new(1000),
+                supply_mode: SUPPLY_MODE_MINT,
+                ..Default::default()
+            };
+            assert!(bond_sale.is_mint_on_demand());
+            assert_eq!(bond_sale.get_unminted_amount(), TokenAmount::new(0));
+        }
+        // partially claimed
+        {
+            let bond_sale = BondSale {
+                supply: TokenAmount::new(1000),
+                remaining_amount: TokenAmount::new(600),
+                minted_amount: TokenAmount::new(150),
+                supply_mode: SUPPLY_MODE_MINT,
+                ..Default::default()
+            };
+            assert_eq!(bond_sale.get_unminted_amount(), TokenAmount::new(250));
+        }
+    }
 }
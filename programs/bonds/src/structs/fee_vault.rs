@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+// fees of every sale quoted in the mint, its token account balance is what the admin can withdraw
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(Debug, Default)]
+pub struct FeeVault {
+    pub token_quote: Pubkey,
+    pub token_account: Pubkey,
+    pub bump: u8,
+}
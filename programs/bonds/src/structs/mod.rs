@@ -1,11 +1,27 @@
 pub mod bond;
 pub mod bond_sale;
 pub mod decimal;
+pub mod fee_vault;
+pub mod price_feed;
+pub mod price_history;
+pub mod proceeds_split;
+pub mod referral;
+pub mod sale_quote;
 pub mod state;
 pub mod token_amount;
+pub mod vesting_term;
+pub mod volume_tier;
 
 pub use bond::*;
 pub use bond_sale::*;
 pub use decimal::*;
+pub use fee_vault::*;
+pub use price_feed::*;
+pub use price_history::*;
+pub use proceeds_split::*;
+pub use referral::*;
+pub use sale_quote::*;
 pub use state::*;
 pub use token_amount::*;
+pub use vesting_term::*;
+pub use volume_tier::*;
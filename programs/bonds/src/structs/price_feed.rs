@@ -0,0 +1,70 @@
+use std::convert::TryInto;
+
+use anchor_lang::prelude::*;
+
+use super::Decimal;
+
+pub const PRICE_FEED_SIZE: usize = 40;
+
+// raw little endian layout of a price feed account, so any program can write one:
+// price: u128 and confidence: u128 with the precision of Decimal, publish_time: u64
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PriceFeed {
+    pub price: Decimal,
+    pub confidence: Decimal,
+    pub publish_time: u64,
+}
+
+impl PriceFeed {
+    pub fn unpack(data: &[u8]) -> Option<PriceFeed> {
+        if data.len() < PRICE_FEED_SIZE {
+            return None;
+        }
+
+        Some(PriceFeed {
+            price: Decimal::new(u128::from_le_bytes(data[0..16].try_into().unwrap())),
+            confidence: Decimal::new(u128::from_le_bytes(data[16..32].try_into().unwrap())),
+            publish_time: u64::from_le_bytes(data[32..40].try_into().unwrap()),
+        })
+    }
+
+    // only the oracle program can write the feed, any other owner could fake the price
+    pub fn load(
+        info: &AccountInfo,
+        oracle_program: &Pubkey,
+    ) -> std::result::Result<PriceFeed, ProgramError> {
+        require!(info.owner == oracle_program, InvalidOracle);
+
+        let data = info.try_borrow_data()?;
+        Ok(PriceFeed::unpack(&data).ok_or(crate::ErrorCode::InvalidOracle)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpack() {
+        {
+            let mut data = [0u8; PRICE_FEED_SIZE];
+            data[0..16].copy_from_slice(&Decimal::from_integer(2).v.to_le_bytes());
+            data[16..32].copy_from_slice(&Decimal::from_decimal(1, 2).v.to_le_bytes());
+            data[32..40].copy_from_slice(&100u64.to_le_bytes());
+
+            assert_eq!(
+                PriceFeed::unpack(&data),
+                Some(PriceFeed {
+                    price: Decimal::from_integer(2),
+                    confidence: Decimal::from_decimal(1, 2),
+                    publish_time: 100
+                })
+            );
+        }
+        // too short
+        {
+            let data = [0u8; PRICE_FEED_SIZE - 1];
+            assert_eq!(PriceFeed::unpack(&data), None);
+        }
+    }
+}
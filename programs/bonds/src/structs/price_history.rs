@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use super::Decimal;
+
+pub const MAX_OBSERVATIONS: usize = 32;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Observation {
+    pub timestamp: u64,
+    pub price_cumulative: u128,
+}
+
+// ring buffer of accumulator snapshots recorded by poke, head is the latest one
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(Debug, Default)]
+pub struct PriceHistory {
+    pub bond_sale: Pubkey,
+    pub head: u8,
+    pub count: u8,
+    pub observations: [Observation; MAX_OBSERVATIONS],
+    pub bump: u8,
+}
+
+impl Observation {
+    // average price between two snapshots, the accumulator is allowed to wrap
+    pub fn get_average_price(&self, later: &Observation) -> Option<Decimal> {
+        let elapsed = later.timestamp.checked_sub(self.timestamp)?;
+        if elapsed == 0 {
+            return None;
+        }
+
+        let delta = { later.price_cumulative }.wrapping_sub(self.price_cumulative);
+        Some(Decimal::new(delta / elapsed as u128))
+    }
+}
+
+impl PriceHistory {
+    pub fn get_latest(&self) -> Option<Observation> {
+        match self.count {
+            0 => None,
+            _ => Some(self.observations[self.head as usize]),
+        }
+    }
+
+    // one snapshot per timestamp, the oldest one is overwritten once the buffer is full
+    pub fn record(&mut self, timestamp: u64, price_cumulative: u128) {
+        if let Some(latest) = self.get_latest() {
+            if latest.timestamp == timestamp {
+                return;
+            }
+        }
+
+        let head = match self.count {
+            0 => 0,
+            _ => (self.head as usize + 1) % MAX_OBSERVATIONS,
+        };
+        self.observations[head] = Observation {
+            timestamp,
+            price_cumulative,
+        };
+        self.head = head as u8;
+        if (self.count as usize) < MAX_OBSERVATIONS {
+            self.count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_average_price() {
+        let first = Observation {
+            timestamp: 100,
+            price_cumulative: 1_000,
+        };
+        let second = Observation {
+            timestamp: 110,
+            price_cumulative: 1_500,
+        };
+        assert_eq!(first.get_average_price(&second), Some(Decimal::new(50)));
+        // same or older snapshot
+        assert_eq!(first.get_average_price(&first), None);
+        assert_eq!(second.get_average_price(&first), None);
+        // accumulator wrapped around
+        {
+            let first = Observation {
+                timestamp: 100,
+                price_cumulative: u128::MAX - 99,
+            };
+            let second = Observation {
+                timestamp: 110,
+                price_cumulative: 400,
+            };
+            assert_eq!(first.get_average_price(&second), Some(Decimal::new(50)));
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut price_history = PriceHistory::default();
+        assert_eq!(price_history.get_latest(), None);
+
+        price_history.record(10, 100);
+        assert_eq!({ price_history.head }, 0);
+        assert_eq!({ price_history.count }, 1);
+        assert_eq!(
+            price_history.get_latest(),
+            Some(Observation {
+                timestamp: 10,
+                price_cumulative: 100
+            })
+        );
+
+        // same timestamp is recorded once
+        price_history.record(10, 200);
+        assert_eq!({ price_history.count }, 1);
+        assert_eq!({ price_history.observations[0].price_cumulative }, 100);
+
+        // wraps around and overwrites the oldest
+        for i in 1..=MAX_OBSERVATIONS as u64 {
+            price_history.record(10 + i, 100 + i as u128);
+        }
+        assert_eq!({ price_history.head }, 0);
+        assert_eq!(price_history.count as usize, MAX_OBSERVATIONS);
+        assert_eq!(
+            price_history.get_latest(),
+            Some(Observation {
+                timestamp: 10 + MAX_OBSERVATIONS as u64,
+                price_cumulative: 100 + MAX_OBSERVATIONS as u128
+            })
+        );
+        assert_eq!({ price_history.observations[1].timestamp }, 11);
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use super::TokenAmount;
+
+pub const MAX_PROCEEDS_SPLITS: usize = 4;
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ProceedsSplit {
+    pub recipient: Pubkey,
+    pub bps: u16,
+    pub distributed: TokenAmount,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ProceedsSplitParams {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+impl ProceedsSplit {
+    pub fn is_active(&self) -> bool {
+        self.bps != 0
+    }
+
+    pub fn get_share(&self, total_released: TokenAmount) -> TokenAmount {
+        TokenAmount::new(
+            (total_released.v as u128)
+                .checked_mul(self.bps as u128)
+                .unwrap()
+                .checked_div(BPS_DENOMINATOR as u128)
+                .unwrap() as u64,
+        )
+    }
+
+    // share of everything released so far that was not sent to the recipient yet
+    pub fn get_undistributed(&self, total_released: TokenAmount) -> TokenAmount {
+        let share = self.get_share(total_released).v;
+
+        match share > self.distributed.v {
+            true => TokenAmount::new(share - self.distributed.v),
+            false => TokenAmount::new(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_undistributed() {
+        {
+            let split = ProceedsSplit {
+                bps: 2500,
+                ..Default::default()
+            };
+            assert_eq!(
+                split.get_undistributed(TokenAmount::new(100)),
+                TokenAmount::new(25)
+            );
+        }
+        // rounding down
+        {
+            let split = ProceedsSplit {
+                bps: 3333,
+                ..Default::default()
+            };
+            assert_eq!(
+                split.get_undistributed(TokenAmount::new(100)),
+                TokenAmount::new(33)
+            );
+        }
+        // partially distributed
+        {
+            let split = ProceedsSplit {
+                bps: 5000,
+                distributed: TokenAmount::new(20),
+                ..Default::default()
+            };
+            assert_eq!(
+                split.get_undistributed(TokenAmount::new(100)),
+                TokenAmount::new(30)
+            );
+            assert_eq!(
+                split.get_undistributed(TokenAmount::new(40)),
+                TokenAmount::new(0)
+            );
+        }
+    }
+}
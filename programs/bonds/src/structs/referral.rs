@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use super::TokenAmount;
+
+// referrers can claim for 30 days after the sale ends, the rest goes to the proceeds destination
+pub const REFERRAL_CLAIM_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(Debug, Default)]
+pub struct Referral {
+    pub bond_sale: Pubkey,
+    pub referrer_quote_account: Pubkey,
+    pub amount: TokenAmount,
+    pub bump: u8,
+    pub referrer: Pubkey,
+}
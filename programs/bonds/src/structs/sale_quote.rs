@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use super::{Decimal, ProceedsSplit, TokenAmount, MAX_PROCEEDS_SPLITS};
+
+// quote token accepted by a sale next to its main token_quote, which is the pricing unit
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(Debug, Default)]
+pub struct SaleQuote {
+    pub bond_sale: Pubkey,
+    pub token_quote: Pubkey,
+    pub token_quote_account: Pubkey,
+    pub proceeds_destination: Pubkey,
+    pub rate: Decimal,
+    pub quote_amount: TokenAmount,
+    pub quote_raised: TokenAmount,
+    pub bump: u8,
+    pub splits_distributed: [TokenAmount; MAX_PROCEEDS_SPLITS],
+}
+
+impl SaleQuote {
+    // rate is the value of one token in the pricing unit, buyers pay the rounding
+    pub fn get_quote_amount(&self, pricing_amount: TokenAmount) -> TokenAmount {
+        pricing_amount.big_div_up(self.rate).to_token_ceil()
+    }
+
+    pub fn get_pricing_amount(&self, quote_amount: TokenAmount) -> TokenAmount {
+        quote_amount.big_mul(self.rate).to_token_floor()
+    }
+
+    // same shares as the main quote token, what was sent to each split is tracked here
+    // since the amounts are in another mint
+    pub fn distribute_quote(
+        &mut self,
+        splits: &[ProceedsSplit; MAX_PROCEEDS_SPLITS],
+        released: TokenAmount,
+    ) -> ([TokenAmount; MAX_PROCEEDS_SPLITS], TokenAmount) {
+        let total_released = self.quote_raised - self.quote_amount + released;
+        let mut splits_distributed = { self.splits_distributed };
+        let mut split_amounts = [TokenAmount::new(0); MAX_PROCEEDS_SPLITS];
+        let mut destination_amount = released;
+
+        for ((split, distributed), split_amount) in splits
+            .iter()
+            .zip(splits_distributed.iter_mut())
+            .zip(split_amounts.iter_mut())
+        {
+            if !split.is_active() {
+                continue;
+            }
+            let undistributed = split
+                .get_share(total_released)
+                .v
+                .saturating_sub(distributed.v);
+            *split_amount = TokenAmount::new(undistributed.min(destination_amount.v));
+
+            *distributed += *split_amount;
+            destination_amount -= *split_amount;
+        }
+
+        self.splits_distributed = splits_distributed;
+        self.quote_amount -= released;
+        (split_amounts, destination_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        {
+            let sale_quote = SaleQuote {
+                rate: Decimal::one(),
+                ..Default::default()
+            };
+            assert_eq!(
+                sale_quote.get_quote_amount(TokenAmount::new(100)),
+                TokenAmount::new(100)
+            );
+            assert_eq!(
+                sale_quote.get_pricing_amount(TokenAmount::new(100)),
+                TokenAmount::new(100)
+            );
+        }
+        // one token is worth two pricing units
+        {
+            let sale_quote = SaleQuote {
+                rate: Decimal::from_integer(2),
+                ..Default::default()
+            };
+            assert_eq!(
+                sale_quote.get_quote_amount(TokenAmount::new(101)),
+                TokenAmount::new(51)
+            );
+            assert_eq!(
+                sale_quote.get_pricing_amount(TokenAmount::new(51)),
+                TokenAmount::new(102)
+            );
+        }
+        // one token is worth a third of a pricing unit
+        {
+            let sale_quote = SaleQuote {
+                rate: Decimal::from_decimal(333_333, 6),
+                ..Default::default()
+            };
+            assert_eq!(
+                sale_quote.get_quote_amount(TokenAmount::new(1)),
+                TokenAmount::new(4)
+            );
+            assert_eq!(
+                sale_quote.get_pricing_amount(TokenAmount::new(4)),
+                TokenAmount::new(1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_distribute_quote() {
+        let mut splits = [ProceedsSplit::default(); MAX_PROCEEDS_SPLITS];
+        splits[0].bps = 2500;
+        splits[2].bps = 5000;
+        let mut sale_quote = SaleQuote {
+            quote_amount: TokenAmount::new(100),
+            quote_raised: TokenAmount::new(100),
+            ..Default::default()
+        };
+
+        let (split_amounts, destination_amount) =
+            sale_quote.distribute_quote(&splits, TokenAmount::new(40));
+        assert_eq!(split_amounts[0], TokenAmount::new(10));
+        assert_eq!(split_amounts[1], TokenAmount::new(0));
+        assert_eq!(split_amounts[2], TokenAmount::new(20));
+        assert_eq!(destination_amount, TokenAmount::new(10));
+        assert_eq!({ sale_quote.quote_amount }, TokenAmount::new(60));
+
+        // the rest is released, splits only get what is left of their share
+        let (split_amounts, destination_amount) =
+            sale_quote.distribute_quote(&splits, TokenAmount::new(60));
+        assert_eq!(split_amounts[0], TokenAmount::new(15));
+        assert_eq!(split_amounts[2], TokenAmount::new(30));
+        assert_eq!(destination_amount, TokenAmount::new(15));
+        assert_eq!({ sale_quote.splits_distributed[0] }, TokenAmount::new(25));
+        assert_eq!({ sale_quote.splits_distributed[2] }, TokenAmount::new(50));
+        assert_eq!({ sale_quote.quote_amount }, TokenAmount::new(0));
+    }
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use super::TokenAmount;
+
+// fee totals are lifetime sums over every fee vault
 #[account(zero_copy)]
 #[repr(packed)]
 #[derive(Debug, Default)]
@@ -9,4 +12,6 @@ pub struct State {
     pub next_bond_sale: u128,
     pub nonce: u8,
     pub bump: u8,
+    pub total_fee: TokenAmount,
+    pub total_withdrawn: TokenAmount,
 }
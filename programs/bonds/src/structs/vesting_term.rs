@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use super::Decimal;
+
+pub const MAX_VESTING_TERMS: usize = 4;
+
+// vesting time a buyer can pick instead of the sale vesting time, longer terms buy at a discount
+#[zero_copy]
+#[repr(packed)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct VestingTerm {
+    pub vesting_time: u64,
+    pub discount: Decimal,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct VestingTermParams {
+    pub vesting_time: u64,
+    pub discount: u128,
+}
+
+impl VestingTerm {
+    pub fn is_active(&self) -> bool {
+        self.vesting_time != 0
+    }
+
+    pub fn apply_discount(&self, price: Decimal) -> Decimal {
+        price * (Decimal::one() - self.discount)
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use super::{Decimal, TokenAmount};
+
+pub const MAX_VOLUME_TIERS: usize = 4;
+
+// purchases of at least min_amount bond tokens get discount off the execution price
+#[zero_copy]
+#[repr(packed)]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct VolumeTier {
+    pub min_amount: TokenAmount,
+    pub discount: Decimal,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct VolumeTierParams {
+    pub min_amount: u64,
+    pub discount: u128,
+}
+
+impl VolumeTier {
+    pub fn is_active(&self) -> bool {
+        !self.min_amount.is_zero()
+    }
+}
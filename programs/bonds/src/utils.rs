@@ -4,11 +4,29 @@ use std::io::Write;
 use anchor_lang::__private::{ErrorCode, CLOSED_ACCOUNT_DISCRIMINATOR};
 use anchor_lang::prelude::*;
 use anchor_lang::prelude::{Clock, SolanaSysvar};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{
+    close_account, initialize_account, transfer, CloseAccount, InitializeAccount, TokenAccount,
+    Transfer,
+};
+
+use crate::interfaces::{TransferSplit, UnwrapQuote};
+use crate::structs::{ProceedsSplit, TokenAmount, MAX_PROCEEDS_SPLITS};
 
 pub fn get_current_timestamp() -> u64 {
     Clock::get().unwrap().unix_timestamp.try_into().unwrap()
 }
 
+pub fn check_expiration(current_time: u64, expires_at: Option<u64>) -> ProgramResult {
+    if let Some(expires_at) = expires_at {
+        require!(current_time <= expires_at, TransactionExpired);
+    }
+    Ok(())
+}
+
 pub fn close<'info>(
     info: AccountInfo<'info>,
     sol_destination: AccountInfo<'info>,
@@ -28,3 +46,174 @@ pub fn close<'info>(
         .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
     Ok(())
 }
+
+// recipients of active splits are passed in order as remaining accounts
+pub fn transfer_to_splits<'info, T: TransferSplit<'info>>(
+    accounts: &T,
+    recipients: &[AccountInfo<'info>],
+    splits: &[ProceedsSplit; MAX_PROCEEDS_SPLITS],
+    split_amounts: &[TokenAmount; MAX_PROCEEDS_SPLITS],
+    signer: &[&[&[u8]]],
+) -> ProgramResult {
+    let mut recipients = recipients.iter();
+    for (split, split_amount) in splits.iter().zip(split_amounts.iter()) {
+        if !split.is_active() {
+            continue;
+        }
+        let recipient = recipients
+            .next()
+            .ok_or(crate::ErrorCode::InvalidSplitRecipient)?;
+        require!(recipient.key() == split.recipient, InvalidSplitRecipient);
+
+        if split_amount.v != 0 {
+            transfer(
+                accounts
+                    .transfer_split(recipient.clone())
+                    .with_signer(signer),
+                split_amount.v,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// split recipients hold the main quote token, their share of a sale quote goes to
+// the associated token account of the same owner, so every active split passes both accounts
+pub fn transfer_to_sale_quote_splits<'info, T: TransferSplit<'info>>(
+    accounts: &T,
+    recipients: &[AccountInfo<'info>],
+    splits: &[ProceedsSplit; MAX_PROCEEDS_SPLITS],
+    split_amounts: &[TokenAmount; MAX_PROCEEDS_SPLITS],
+    token_quote: &Pubkey,
+    signer: &[&[&[u8]]],
+) -> ProgramResult {
+    let mut recipients = recipients.iter();
+    for (split, split_amount) in splits.iter().zip(split_amounts.iter()) {
+        if !split.is_active() {
+            continue;
+        }
+        let recipient = recipients
+            .next()
+            .ok_or(crate::ErrorCode::InvalidSplitRecipient)?;
+        let destination = recipients
+            .next()
+            .ok_or(crate::ErrorCode::InvalidSplitRecipient)?;
+        require!(recipient.key() == split.recipient, InvalidSplitRecipient);
+
+        let recipient = Account::<TokenAccount>::try_from(recipient)?;
+        require!(
+            destination.key() == get_associated_token_address(&recipient.owner, token_quote),
+            InvalidSplitRecipient
+        );
+
+        if split_amount.v != 0 {
+            transfer(
+                accounts
+                    .transfer_split(destination.clone())
+                    .with_signer(signer),
+                split_amount.v,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// wallets get native SOL, token accounts get wSOL
+pub fn is_native_destination(destination: &AccountInfo) -> bool {
+    *destination.owner == system_program::ID
+}
+
+// wSOL is unwrapped by closing a token account, so the amount passes through a temporary
+// account funded by the payer, closed back into the payer and forwarded to the destination
+pub fn transfer_unwrapped<'info, T: UnwrapQuote<'info>>(
+    accounts: &T,
+    amount: u64,
+    unwrap_signer: &[&[&[u8]]],
+    signer: &[&[&[u8]]],
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+    let accounts = accounts.unwrap_quote();
+    let space = spl_token::state::Account::LEN;
+
+    // lamports sent to the address beforehand must not block the account creation
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(accounts.unwrap_account.lamports());
+    if lamports != 0 {
+        invoke(
+            &system_instruction::transfer(
+                accounts.payer.key,
+                accounts.unwrap_account.key,
+                lamports,
+            ),
+            &[
+                accounts.payer.clone(),
+                accounts.unwrap_account.clone(),
+                accounts.system_program.clone(),
+            ],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(accounts.unwrap_account.key, space as u64),
+        &[
+            accounts.unwrap_account.clone(),
+            accounts.system_program.clone(),
+        ],
+        unwrap_signer,
+    )?;
+    invoke_signed(
+        &system_instruction::assign(accounts.unwrap_account.key, &spl_token::ID),
+        &[
+            accounts.unwrap_account.clone(),
+            accounts.system_program.clone(),
+        ],
+        unwrap_signer,
+    )?;
+
+    initialize_account(CpiContext::new(
+        accounts.token_program.clone(),
+        InitializeAccount {
+            account: accounts.unwrap_account.clone(),
+            mint: accounts.token_quote.clone(),
+            authority: accounts.authority.clone(),
+            rent: accounts.rent.clone(),
+        },
+    ))?;
+    transfer(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            Transfer {
+                from: accounts.from,
+                to: accounts.unwrap_account.clone(),
+                authority: accounts.authority.clone(),
+            },
+        )
+        .with_signer(signer),
+        amount,
+    )?;
+    close_account(
+        CpiContext::new(
+            accounts.token_program,
+            CloseAccount {
+                account: accounts.unwrap_account,
+                destination: accounts.payer.clone(),
+                authority: accounts.authority,
+            },
+        )
+        .with_signer(signer),
+    )?;
+
+    if accounts.destination.key == accounts.payer.key {
+        return Ok(());
+    }
+    invoke(
+        &system_instruction::transfer(accounts.payer.key, accounts.destination.key, amount),
+        &[
+            accounts.payer,
+            accounts.destination,
+            accounts.system_program,
+        ],
+    )
+}
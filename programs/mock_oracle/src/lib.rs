@@ -0,0 +1,35 @@
+use std::convert::TryInto;
+
+use anchor_lang::prelude::*;
+
+declare_id!("5Nu1pqJd7mGNxMf1PwSUCPjW4LFgcHyxop3v3SqDyDUV");
+
+// writes the raw price feed layout read by bonds:
+// price: u128, confidence: u128, publish_time: u64, little endian
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    pub fn set_price(
+        ctx: Context<SetPrice>,
+        price: u128,
+        confidence: u128,
+        publish_time: Option<u64>,
+    ) -> ProgramResult {
+        let publish_time = publish_time
+            .unwrap_or_else(|| Clock::get().unwrap().unix_timestamp.try_into().unwrap());
+
+        let mut data = ctx.accounts.price_feed.try_borrow_mut_data()?;
+        data[0..16].copy_from_slice(&price.to_le_bytes());
+        data[16..32].copy_from_slice(&confidence.to_le_bytes());
+        data[32..40].copy_from_slice(&publish_time.to_le_bytes());
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    #[account(mut, owner = crate::ID)]
+    pub price_feed: AccountInfo<'info>,
+}
@@ -48,7 +48,7 @@ export type Bonds = {
         },
         {
           "name": "tokenBond",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -71,6 +71,11 @@ export type Bonds = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "proceedsDestination",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "payer",
           "isMut": true,
@@ -121,12 +126,41 @@ export type Bonds = {
         {
           "name": "vestingTime",
           "type": "u64"
+        },
+        {
+          "name": "softCap",
+          "type": "u64"
+        },
+        {
+          "name": "hardCap",
+          "type": "u64"
+        },
+        {
+          "name": "proceedsVestingTime",
+          "type": "u64"
+        },
+        {
+          "name": "proceedsSplits",
+          "type": {
+            "vec": {
+              "defined": "ProceedsSplitParams"
+            }
+          }
+        },
+        {
+          "name": "mintOnDemand",
+          "type": "bool"
         }
       ]
     },
     {
       "name": "createBond",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "bondSale",
           "isMut": true,
@@ -135,7 +169,7 @@ export type Bonds = {
         {
           "name": "bond",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "ownerQuoteAccount",
@@ -152,11 +186,41 @@ export type Bonds = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "owner",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -181,6 +245,125 @@ export type Bonds = {
         {
           "name": "priceLimit",
           "type": "u128"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "vestingTerm",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createBondExactIn",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "quoteAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minBondOut",
+          "type": "u64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "vestingTerm",
+          "type": {
+            "option": "u8"
+          }
         }
       ]
     },
@@ -208,17 +391,27 @@ export type Bonds = {
           "isSigner": false
         },
         {
-          "name": "payerQuoteAccount",
+          "name": "tokenBond",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payerBondAccount",
+          "name": "proceedsDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "adminQuoteAccount",
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBondAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -236,6 +429,16 @@ export type Bonds = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -301,13 +504,23 @@ export type Bonds = {
           "isSigner": false
         },
         {
-          "name": "payerQuoteAccount",
+          "name": "proceedsDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "tokenQuote",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -319,12 +532,22 @@ export type Bonds = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "claimBond",
+      "name": "refundBond",
       "accounts": [
         {
           "name": "state",
@@ -333,7 +556,7 @@ export type Bonds = {
         },
         {
           "name": "bondSale",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -342,61 +565,60 @@ export type Bonds = {
           "isSigner": false
         },
         {
-          "name": "tokenBondAccount",
+          "name": "tokenQuoteAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ownerBondAccount",
+          "name": "ownerQuoteAccount",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "owner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "changeFee",
-      "accounts": [
+        },
         {
-          "name": "state",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bondSale",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "rent",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "newFee",
-          "type": "u128"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "withdrawFee",
+      "name": "claimBond",
       "accounts": [
         {
           "name": "state",
@@ -409,19 +631,34 @@ export type Bonds = {
           "isSigner": false
         },
         {
-          "name": "tokenQuoteAccount",
+          "name": "bond",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "adminQuoteAccount",
+          "name": "tokenBondAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "tokenBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "authority",
@@ -434,278 +671,67 @@ export type Bonds = {
           "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "bond",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bondSale",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenBond",
-            "type": "publicKey"
-          },
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "bondAmount",
-            "type": {
-              "defined": "TokenAmount"
-            }
-          },
-          {
-            "name": "lastClaim",
-            "type": "u64"
-          },
-          {
-            "name": "vestingStart",
-            "type": "u64"
-          },
-          {
-            "name": "vestingEnd",
-            "type": "u64"
-          },
-          {
-            "name": "id",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "bondSale",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokenBond",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenQuote",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenBondAccount",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenQuoteAccount",
-            "type": "publicKey"
-          },
-          {
-            "name": "payer",
-            "type": "publicKey"
-          },
-          {
-            "name": "fee",
-            "type": {
-              "defined": "Decimal"
-            }
-          },
-          {
-            "name": "feeAmount",
-            "type": {
-              "defined": "TokenAmount"
-            }
-          },
-          {
-            "name": "floorPrice",
-            "type": {
-              "defined": "Decimal"
-            }
-          },
-          {
-            "name": "previousPrice",
-            "type": {
-              "defined": "Decimal"
-            }
-          },
-          {
-            "name": "upBound",
-            "type": {
-              "defined": "Decimal"
-            }
-          },
-          {
-            "name": "velocity",
-            "type": {
-              "defined": "Decimal"
-            }
-          },
-          {
-            "name": "supply",
-            "type": {
-              "defined": "TokenAmount"
-            }
-          },
-          {
-            "name": "remainingAmount",
-            "type": {
-              "defined": "TokenAmount"
-            }
-          },
-          {
-            "name": "quoteAmount",
-            "type": {
-              "defined": "TokenAmount"
-            }
-          },
-          {
-            "name": "endTime",
-            "type": "u64"
-          },
-          {
-            "name": "startTime",
-            "type": "u64"
-          },
-          {
-            "name": "lastTrade",
-            "type": "u64"
-          },
-          {
-            "name": "vestingTime",
-            "type": "u64"
-          },
-          {
-            "name": "nextBond",
-            "type": "u128"
-          },
-          {
-            "name": "id",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "state",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "nextBondSale",
-            "type": "u128"
-          },
-          {
-            "name": "nonce",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Decimal",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "TokenAmount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u64"
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
           }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidPoolTokenAddresses",
-      "msg": "Invalid pool token addresses"
-    },
-    {
-      "code": 6001,
-      "name": "InsufficientTokenAmount",
-      "msg": "Buy amount exceeds remaining amount"
-    },
-    {
-      "code": 6002,
-      "name": "VestingEnded",
-      "msg": "Vesting ended"
+        }
+      ]
     },
     {
-      "code": 6003,
-      "name": "PriceLimitExceeded",
-      "msg": "Actual price exceeded price limit"
-    }
-  ]
-};
-
-export const IDL: Bonds = {
-  "version": "0.1.0",
-  "name": "bonds",
-  "instructions": [
-    {
-      "name": "createState",
+      "name": "closeBond",
       "accounts": [
         {
-          "name": "state",
+          "name": "bond",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "owner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setBondDelegate",
+      "accounts": [
+        {
+          "name": "bond",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "tip",
+          "type": "u128"
         }
       ]
     },
     {
-      "name": "initBondSale",
+      "name": "claimBondDelegated",
       "accounts": [
         {
           "name": "state",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -714,35 +740,45 @@ export const IDL: Bonds = {
           "isSigner": false
         },
         {
-          "name": "tokenBond",
-          "isMut": false,
+          "name": "bond",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenQuote",
-          "isMut": false,
+          "name": "tokenBondAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBondAccount",
+          "name": "tokenBond",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenQuoteAccount",
+          "name": "ownerBondAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "payerBondAccount",
+          "name": "delegateBondAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -752,77 +788,98 @@ export const IDL: Bonds = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeFee",
+      "accounts": [
         {
-          "name": "systemProgram",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "bondSale",
+          "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "floorPrice",
+          "name": "newFee",
           "type": "u128"
-        },
+        }
+      ]
+    },
+    {
+      "name": "changeFeeMode",
+      "accounts": [
         {
-          "name": "upBound",
-          "type": "u128"
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "velocity",
-          "type": "u128"
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "supply",
-          "type": "u64"
-        },
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
         {
-          "name": "duration",
-          "type": "u64"
+          "name": "feeMode",
+          "type": "u8"
         },
         {
-          "name": "vestingTime",
-          "type": "u64"
+          "name": "bondFee",
+          "type": "u128"
         }
       ]
     },
     {
-      "name": "createBond",
+      "name": "createFeeVault",
       "accounts": [
         {
-          "name": "bondSale",
-          "isMut": true,
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bond",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ownerQuoteAccount",
-          "isMut": true,
+          "name": "tokenQuote",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBondAccount",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenQuoteAccount",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "tokenProgram",
@@ -840,65 +897,82 @@ export const IDL: Bonds = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "priceLimit",
-          "type": "u128"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "endBondSale",
+      "name": "withdrawFee",
       "accounts": [
         {
           "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bondSale",
+          "name": "feeVaultTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenQuoteAccount",
+          "name": "adminQuoteAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBondAccount",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerQuoteAccount",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawBondFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerBondAccount",
+          "name": "bondSale",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "adminQuoteAccount",
+          "name": "tokenBondAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "adminBondAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -908,49 +982,64 @@ export const IDL: Bonds = {
       "args": []
     },
     {
-      "name": "changeVelocity",
+      "name": "changeReferralFee",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "bondSale",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "velocity",
+          "name": "referralFee",
           "type": "u128"
         }
       ]
     },
     {
-      "name": "changeUpBound",
+      "name": "createReferral",
       "accounts": [
         {
           "name": "bondSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "referrerQuoteAccount",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "upBound",
-          "type": "u128"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "claimQuote",
+      "name": "claimReferral",
       "accounts": [
         {
           "name": "state",
@@ -963,17 +1052,22 @@ export const IDL: Bonds = {
           "isSigner": false
         },
         {
-          "name": "bondSaleQuoteAccount",
+          "name": "referral",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payerQuoteAccount",
+          "name": "tokenQuoteAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "referrerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
           "isMut": false,
           "isSigner": true
         },
@@ -991,7 +1085,7 @@ export const IDL: Bonds = {
       "args": []
     },
     {
-      "name": "claimBond",
+      "name": "addSaleQuote",
       "accounts": [
         {
           "name": "state",
@@ -1000,27 +1094,37 @@ export const IDL: Bonds = {
         },
         {
           "name": "bondSale",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bond",
+          "name": "saleQuote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBondAccount",
-          "isMut": true,
+          "name": "tokenQuote",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerBondAccount",
+          "name": "tokenQuoteAccount",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bondSaleProceedsDestination",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "proceedsDestination",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1032,38 +1136,129 @@ export const IDL: Bonds = {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "changeFee",
-      "accounts": [
+        },
         {
-          "name": "state",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bondSale",
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rate",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "createBondWithSaleQuote",
+      "accounts": [
+        {
+          "name": "state",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "saleQuote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newFee",
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "priceLimit",
           "type": "u128"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "vestingTerm",
+          "type": {
+            "option": "u8"
+          }
         }
       ]
     },
     {
-      "name": "withdrawFee",
+      "name": "claimSaleQuote",
       "accounts": [
         {
           "name": "state",
@@ -1075,19 +1270,24 @@ export const IDL: Bonds = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "saleQuote",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenQuoteAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "adminQuoteAccount",
+          "name": "proceedsDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
-          "isMut": false,
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1102,207 +1302,3489 @@ export const IDL: Bonds = {
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "bond",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bondSale",
-            "type": "publicKey"
-          },
+      "name": "changePriceMode",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "priceMode",
+          "type": "u8"
+        },
+        {
+          "name": "discount",
+          "type": "u128"
+        },
+        {
+          "name": "maxStaleness",
+          "type": "u64"
+        },
+        {
+          "name": "maxConfidence",
+          "type": "u128"
+        },
+        {
+          "name": "oracleProgram",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setDebtPricing",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "controlVariable",
+          "type": "u128"
+        },
+        {
+          "name": "maxPayout",
+          "type": "u64"
+        },
+        {
+          "name": "maxDebt",
+          "type": "u64"
+        },
+        {
+          "name": "adjustmentTarget",
+          "type": "u128"
+        },
+        {
+          "name": "adjustmentRate",
+          "type": "u128"
+        },
+        {
+          "name": "adjustmentBuffer",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setVelocityController",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gain",
+          "type": "u128"
+        },
+        {
+          "name": "minVelocity",
+          "type": "u128"
+        },
+        {
+          "name": "maxVelocity",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "changeCeilMode",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ceilMode",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createPriceHistory",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pokePrice",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setVolumeTiers",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "volumeTiers",
+          "type": {
+            "vec": {
+              "defined": "VolumeTierParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setVestingTerms",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vestingTerms",
+          "type": {
+            "vec": {
+              "defined": "VestingTermParams"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bond",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBond",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "bondAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "lastClaim",
+            "type": "u64"
+          },
+          {
+            "name": "vestingStart",
+            "type": "u64"
+          },
+          {
+            "name": "vestingEnd",
+            "type": "u64"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "claimedAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegateTip",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "quoteAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "feeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "referralAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "bondFee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "bondFeeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "saleQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "vestingDiscount",
+            "type": {
+              "defined": "Decimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenBond",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBondAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuoteAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "fee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "feeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "floorPrice",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "previousPrice",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "upBound",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "velocity",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "supply",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "quoteAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "u64"
+          },
+          {
+            "name": "lastTrade",
+            "type": "u64"
+          },
+          {
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "nextBond",
+            "type": "u128"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "softCap",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "quoteRaised",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "hardCap",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "status",
+            "type": "u8"
+          },
+          {
+            "name": "proceedsVestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "proceedsDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "proceedsSplits",
+            "type": {
+              "array": [
+                {
+                  "defined": "ProceedsSplit"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "referralFee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "referralAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "feeMode",
+            "type": "u8"
+          },
+          {
+            "name": "bondFee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "bondFeeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "supplyMode",
+            "type": "u8"
+          },
+          {
+            "name": "mintedAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "saleQuoteRaised",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "saleQuoteCount",
+            "type": "u8"
+          },
+          {
+            "name": "priceMode",
+            "type": "u8"
+          },
+          {
+            "name": "oracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleDiscount",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "oracleMaxStaleness",
+            "type": "u64"
+          },
+          {
+            "name": "oracleMaxConfidence",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "controlVariable",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "totalDebt",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "lastDecay",
+            "type": "u64"
+          },
+          {
+            "name": "maxPayout",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "maxDebt",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "adjustmentTarget",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "adjustmentRate",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "adjustmentBuffer",
+            "type": "u64"
+          },
+          {
+            "name": "lastAdjustment",
+            "type": "u64"
+          },
+          {
+            "name": "velocityGain",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "minVelocity",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "maxVelocity",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "ceilMode",
+            "type": "u8"
+          },
+          {
+            "name": "priceCumulative",
+            "type": "u128"
+          },
+          {
+            "name": "lastObservation",
+            "type": "u64"
+          },
+          {
+            "name": "lastObservedPrice",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "volumeTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "VolumeTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "array": [
+                {
+                  "defined": "VestingTerm"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "oracleProgram",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceHistory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "head",
+            "type": "u8"
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "array": [
+                {
+                  "defined": "Observation"
+                },
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referral",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "referrerQuoteAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "saleQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuoteAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "proceedsDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "rate",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "quoteAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "quoteRaised",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "splitsDistributed",
+            "type": {
+              "array": [
+                {
+                  "defined": "TokenAmount"
+                },
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "nextBondSale",
+            "type": "u128"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalFee",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "totalWithdrawn",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Decimal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Observation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "u64"
+          },
+          {
+            "name": "priceCumulative",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProceedsSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          },
+          {
+            "name": "distributed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProceedsSplitParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingTerm",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "discount",
+            "type": {
+              "defined": "Decimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingTermParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "discount",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VolumeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "discount",
+            "type": {
+              "defined": "Decimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VolumeTierParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "discount",
+            "type": "u128"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidPoolTokenAddresses",
+      "msg": "Invalid pool token addresses"
+    },
+    {
+      "code": 6001,
+      "name": "InsufficientTokenAmount",
+      "msg": "Buy amount exceeds remaining amount"
+    },
+    {
+      "code": 6002,
+      "name": "VestingEnded",
+      "msg": "Vesting ended"
+    },
+    {
+      "code": 6003,
+      "name": "PriceLimitExceeded",
+      "msg": "Actual price exceeded price limit"
+    },
+    {
+      "code": 6004,
+      "name": "ClaimAmountExceeded",
+      "msg": "Claim amount exceeds vested amount"
+    },
+    {
+      "code": 6005,
+      "name": "DelegateTipExceeded",
+      "msg": "Delegate tip exceeds maximum"
+    },
+    {
+      "code": 6006,
+      "name": "BondNotFullyClaimed",
+      "msg": "Bond is not fully claimed"
+    },
+    {
+      "code": 6007,
+      "name": "TransactionExpired",
+      "msg": "Transaction expired"
+    },
+    {
+      "code": 6008,
+      "name": "BondOutBelowMinimum",
+      "msg": "Bond amount below minimum"
+    },
+    {
+      "code": 6009,
+      "name": "SoftCapNotReached",
+      "msg": "Soft cap not reached"
+    },
+    {
+      "code": 6010,
+      "name": "RefundNotAvailable",
+      "msg": "Refund not available"
+    },
+    {
+      "code": 6011,
+      "name": "SaleSoldOut",
+      "msg": "Bond sale sold out"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidHardCap",
+      "msg": "Hard cap below soft cap"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidProceedsSplits",
+      "msg": "Invalid proceeds splits"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidSplitRecipient",
+      "msg": "Invalid proceeds split recipient"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidReferralFee",
+      "msg": "Referral fee exceeds fee"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidReferral",
+      "msg": "Referral does not belong to bond sale"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidFeeMode",
+      "msg": "Invalid fee mode"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidQuoteAccount",
+      "msg": "Invalid quote account"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidMintAuthority",
+      "msg": "Payer is not the mint authority"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidRate",
+      "msg": "Invalid conversion rate"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidPriceMode",
+      "msg": "Invalid price mode"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidOracle",
+      "msg": "Invalid price feed account"
+    },
+    {
+      "code": 6023,
+      "name": "StaleOracle",
+      "msg": "Price feed is stale"
+    },
+    {
+      "code": 6024,
+      "name": "OracleConfidenceExceeded",
+      "msg": "Price feed confidence exceeds maximum"
+    },
+    {
+      "code": 6025,
+      "name": "MaxPayoutExceeded",
+      "msg": "Bond amount exceeds maximum payout"
+    },
+    {
+      "code": 6026,
+      "name": "MaxDebtExceeded",
+      "msg": "Debt exceeds maximum"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidVelocityBounds",
+      "msg": "Minimum velocity exceeds maximum"
+    },
+    {
+      "code": 6028,
+      "name": "CeilPriceExceeded",
+      "msg": "Price would exceed the ceiling price"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidCeilMode",
+      "msg": "Invalid ceiling mode"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPriceHistory",
+      "msg": "Price history does not belong to bond sale"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidVolumeTiers",
+      "msg": "Invalid volume tiers"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidVestingTerms",
+      "msg": "Invalid vesting terms"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidVestingTerm",
+      "msg": "Vesting term is not offered"
+    },
+    {
+      "code": 6034,
+      "name": "SelfReferral",
+      "msg": "Buyer cannot refer themselves"
+    },
+    {
+      "code": 6035,
+      "name": "ReferralClaimExpired",
+      "msg": "Referral claim period has expired"
+    }
+  ]
+};
+
+export const IDL: Bonds = {
+  "version": "0.1.0",
+  "name": "bonds",
+  "instructions": [
+    {
+      "name": "createState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initBondSale",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proceedsDestination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "floorPrice",
+          "type": "u128"
+        },
+        {
+          "name": "upBound",
+          "type": "u128"
+        },
+        {
+          "name": "velocity",
+          "type": "u128"
+        },
+        {
+          "name": "supply",
+          "type": "u64"
+        },
+        {
+          "name": "duration",
+          "type": "u64"
+        },
+        {
+          "name": "vestingTime",
+          "type": "u64"
+        },
+        {
+          "name": "softCap",
+          "type": "u64"
+        },
+        {
+          "name": "hardCap",
+          "type": "u64"
+        },
+        {
+          "name": "proceedsVestingTime",
+          "type": "u64"
+        },
+        {
+          "name": "proceedsSplits",
+          "type": {
+            "vec": {
+              "defined": "ProceedsSplitParams"
+            }
+          }
+        },
+        {
+          "name": "mintOnDemand",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createBond",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "priceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "vestingTerm",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createBondExactIn",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "quoteAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minBondOut",
+          "type": "u64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "vestingTerm",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "endBondSale",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proceedsDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeVelocity",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "velocity",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "changeUpBound",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "upBound",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "claimQuote",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSaleQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proceedsDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundBond",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimBond",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeBond",
+      "accounts": [
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setBondDelegate",
+      "accounts": [
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "tip",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "claimBondDelegated",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "delegateBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newFee",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "changeFeeMode",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeMode",
+          "type": "u8"
+        },
+        {
+          "name": "bondFee",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "createFeeVault",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawBondFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeReferralFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "referralFee",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "createReferral",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerQuoteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferral",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSaleQuote",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "saleQuote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bondSaleProceedsDestination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proceedsDestination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rate",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "createBondWithSaleQuote",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "saleQuote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "priceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "vestingTerm",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimSaleQuote",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "saleQuote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proceedsDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changePriceMode",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "priceMode",
+          "type": "u8"
+        },
+        {
+          "name": "discount",
+          "type": "u128"
+        },
+        {
+          "name": "maxStaleness",
+          "type": "u64"
+        },
+        {
+          "name": "maxConfidence",
+          "type": "u128"
+        },
+        {
+          "name": "oracleProgram",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setDebtPricing",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "controlVariable",
+          "type": "u128"
+        },
+        {
+          "name": "maxPayout",
+          "type": "u64"
+        },
+        {
+          "name": "maxDebt",
+          "type": "u64"
+        },
+        {
+          "name": "adjustmentTarget",
+          "type": "u128"
+        },
+        {
+          "name": "adjustmentRate",
+          "type": "u128"
+        },
+        {
+          "name": "adjustmentBuffer",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setVelocityController",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gain",
+          "type": "u128"
+        },
+        {
+          "name": "minVelocity",
+          "type": "u128"
+        },
+        {
+          "name": "maxVelocity",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "changeCeilMode",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ceilMode",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createPriceHistory",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pokePrice",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setVolumeTiers",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "volumeTiers",
+          "type": {
+            "vec": {
+              "defined": "VolumeTierParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setVestingTerms",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vestingTerms",
+          "type": {
+            "vec": {
+              "defined": "VestingTermParams"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bond",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBond",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "bondAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "lastClaim",
+            "type": "u64"
+          },
+          {
+            "name": "vestingStart",
+            "type": "u64"
+          },
+          {
+            "name": "vestingEnd",
+            "type": "u64"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "claimedAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegateTip",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "rentPayer",
+            "type": "publicKey"
+          },
+          {
+            "name": "quoteAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "feeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "referralAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "bondFee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "bondFeeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "saleQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "vestingDiscount",
+            "type": {
+              "defined": "Decimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenBond",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBondAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuoteAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "fee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "feeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "floorPrice",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "previousPrice",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "upBound",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "velocity",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "supply",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "quoteAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "u64"
+          },
+          {
+            "name": "lastTrade",
+            "type": "u64"
+          },
+          {
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "nextBond",
+            "type": "u128"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "softCap",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "quoteRaised",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "hardCap",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "status",
+            "type": "u8"
+          },
+          {
+            "name": "proceedsVestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "proceedsDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "proceedsSplits",
+            "type": {
+              "array": [
+                {
+                  "defined": "ProceedsSplit"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "referralFee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "referralAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "feeMode",
+            "type": "u8"
+          },
+          {
+            "name": "bondFee",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "bondFeeAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "supplyMode",
+            "type": "u8"
+          },
+          {
+            "name": "mintedAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "saleQuoteRaised",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "saleQuoteCount",
+            "type": "u8"
+          },
+          {
+            "name": "priceMode",
+            "type": "u8"
+          },
+          {
+            "name": "oracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleDiscount",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "oracleMaxStaleness",
+            "type": "u64"
+          },
+          {
+            "name": "oracleMaxConfidence",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "controlVariable",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "totalDebt",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "lastDecay",
+            "type": "u64"
+          },
+          {
+            "name": "maxPayout",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "maxDebt",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "adjustmentTarget",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "adjustmentRate",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "adjustmentBuffer",
+            "type": "u64"
+          },
+          {
+            "name": "lastAdjustment",
+            "type": "u64"
+          },
+          {
+            "name": "velocityGain",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "minVelocity",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "maxVelocity",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "ceilMode",
+            "type": "u8"
+          },
+          {
+            "name": "priceCumulative",
+            "type": "u128"
+          },
+          {
+            "name": "lastObservation",
+            "type": "u64"
+          },
+          {
+            "name": "lastObservedPrice",
+            "type": {
+              "defined": "Decimal"
+            }
+          },
+          {
+            "name": "volumeTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "VolumeTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "array": [
+                {
+                  "defined": "VestingTerm"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "oracleProgram",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceHistory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "head",
+            "type": "u8"
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "array": [
+                {
+                  "defined": "Observation"
+                },
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referral",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "tokenBond",
+            "name": "bondSale",
             "type": "publicKey"
           },
           {
-            "name": "owner",
+            "name": "referrerQuoteAccount",
             "type": "publicKey"
           },
           {
-            "name": "bondAmount",
+            "name": "amount",
             "type": {
               "defined": "TokenAmount"
             }
           },
           {
-            "name": "lastClaim",
-            "type": "u64"
-          },
-          {
-            "name": "vestingStart",
-            "type": "u64"
-          },
-          {
-            "name": "vestingEnd",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "id",
-            "type": "u128"
+            "name": "referrer",
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "bondSale",
+      "name": "saleQuote",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenBond",
+            "name": "bondSale",
             "type": "publicKey"
           },
           {
             "name": "tokenQuote",
             "type": "publicKey"
           },
-          {
-            "name": "tokenBondAccount",
-            "type": "publicKey"
-          },
           {
             "name": "tokenQuoteAccount",
             "type": "publicKey"
           },
           {
-            "name": "payer",
+            "name": "proceedsDestination",
             "type": "publicKey"
           },
           {
-            "name": "fee",
+            "name": "rate",
             "type": {
               "defined": "Decimal"
             }
           },
           {
-            "name": "feeAmount",
+            "name": "quoteAmount",
             "type": {
               "defined": "TokenAmount"
             }
           },
           {
-            "name": "floorPrice",
+            "name": "quoteRaised",
             "type": {
-              "defined": "Decimal"
+              "defined": "TokenAmount"
             }
           },
           {
-            "name": "previousPrice",
-            "type": {
-              "defined": "Decimal"
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "upBound",
+            "name": "splitsDistributed",
             "type": {
-              "defined": "Decimal"
+              "array": [
+                {
+                  "defined": "TokenAmount"
+                },
+                4
+              ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
           },
           {
-            "name": "velocity",
-            "type": {
-              "defined": "Decimal"
-            }
+            "name": "authority",
+            "type": "publicKey"
           },
           {
-            "name": "supply",
-            "type": {
-              "defined": "TokenAmount"
-            }
+            "name": "nextBondSale",
+            "type": "u128"
           },
           {
-            "name": "remainingAmount",
+            "name": "nonce",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalFee",
             "type": {
               "defined": "TokenAmount"
             }
           },
           {
-            "name": "quoteAmount",
+            "name": "totalWithdrawn",
             "type": {
               "defined": "TokenAmount"
             }
-          },
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Decimal",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "endTime",
-            "type": "u64"
-          },
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Observation",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "startTime",
+            "name": "timestamp",
             "type": "u64"
           },
           {
-            "name": "lastTrade",
-            "type": "u64"
-          },
+            "name": "priceCumulative",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProceedsSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "vestingTime",
-            "type": "u64"
+            "name": "recipient",
+            "type": "publicKey"
           },
           {
-            "name": "nextBond",
-            "type": "u128"
+            "name": "bps",
+            "type": "u16"
           },
           {
-            "name": "id",
-            "type": "u128"
+            "name": "distributed",
+            "type": {
+              "defined": "TokenAmount"
+            }
           }
         ]
       }
     },
     {
-      "name": "state",
+      "name": "ProceedsSplitParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "recipient",
             "type": "publicKey"
           },
           {
-            "name": "authority",
-            "type": "publicKey"
-          },
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "nextBondSale",
-            "type": "u128"
-          },
+            "name": "v",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingTerm",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "nonce",
-            "type": "u8"
+            "name": "vestingTime",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "discount",
+            "type": {
+              "defined": "Decimal"
+            }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "Decimal",
+      "name": "VestingTermParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "v",
+            "name": "vestingTime",
+            "type": "u64"
+          },
+          {
+            "name": "discount",
             "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "TokenAmount",
+      "name": "VolumeTier",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "v",
+            "name": "minAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "discount",
+            "type": {
+              "defined": "Decimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VolumeTierParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
             "type": "u64"
+          },
+          {
+            "name": "discount",
+            "type": "u128"
           }
         ]
       }
@@ -1328,6 +4810,166 @@ export const IDL: Bonds = {
       "code": 6003,
       "name": "PriceLimitExceeded",
       "msg": "Actual price exceeded price limit"
+    },
+    {
+      "code": 6004,
+      "name": "ClaimAmountExceeded",
+      "msg": "Claim amount exceeds vested amount"
+    },
+    {
+      "code": 6005,
+      "name": "DelegateTipExceeded",
+      "msg": "Delegate tip exceeds maximum"
+    },
+    {
+      "code": 6006,
+      "name": "BondNotFullyClaimed",
+      "msg": "Bond is not fully claimed"
+    },
+    {
+      "code": 6007,
+      "name": "TransactionExpired",
+      "msg": "Transaction expired"
+    },
+    {
+      "code": 6008,
+      "name": "BondOutBelowMinimum",
+      "msg": "Bond amount below minimum"
+    },
+    {
+      "code": 6009,
+      "name": "SoftCapNotReached",
+      "msg": "Soft cap not reached"
+    },
+    {
+      "code": 6010,
+      "name": "RefundNotAvailable",
+      "msg": "Refund not available"
+    },
+    {
+      "code": 6011,
+      "name": "SaleSoldOut",
+      "msg": "Bond sale sold out"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidHardCap",
+      "msg": "Hard cap below soft cap"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidProceedsSplits",
+      "msg": "Invalid proceeds splits"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidSplitRecipient",
+      "msg": "Invalid proceeds split recipient"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidReferralFee",
+      "msg": "Referral fee exceeds fee"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidReferral",
+      "msg": "Referral does not belong to bond sale"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidFeeMode",
+      "msg": "Invalid fee mode"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidQuoteAccount",
+      "msg": "Invalid quote account"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidMintAuthority",
+      "msg": "Payer is not the mint authority"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidRate",
+      "msg": "Invalid conversion rate"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidPriceMode",
+      "msg": "Invalid price mode"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidOracle",
+      "msg": "Invalid price feed account"
+    },
+    {
+      "code": 6023,
+      "name": "StaleOracle",
+      "msg": "Price feed is stale"
+    },
+    {
+      "code": 6024,
+      "name": "OracleConfidenceExceeded",
+      "msg": "Price feed confidence exceeds maximum"
+    },
+    {
+      "code": 6025,
+      "name": "MaxPayoutExceeded",
+      "msg": "Bond amount exceeds maximum payout"
+    },
+    {
+      "code": 6026,
+      "name": "MaxDebtExceeded",
+      "msg": "Debt exceeds maximum"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidVelocityBounds",
+      "msg": "Minimum velocity exceeds maximum"
+    },
+    {
+      "code": 6028,
+      "name": "CeilPriceExceeded",
+      "msg": "Price would exceed the ceiling price"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidCeilMode",
+      "msg": "Invalid ceiling mode"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPriceHistory",
+      "msg": "Price history does not belong to bond sale"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidVolumeTiers",
+      "msg": "Invalid volume tiers"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidVestingTerms",
+      "msg": "Invalid vesting terms"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidVestingTerm",
+      "msg": "Vesting term is not offered"
+    },
+    {
+      "code": 6034,
+      "name": "SelfReferral",
+      "msg": "Buyer cannot refer themselves"
+    },
+    {
+      "code": 6035,
+      "name": "ReferralClaimExpired",
+      "msg": "Referral claim period has expired"
     }
   ]
 };
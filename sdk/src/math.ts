@@ -1,19 +1,112 @@
 import { BN } from '@project-serum/anchor'
-import { BondSaleStruct, BondStruct, Decimal } from './sale'
+import { BondSaleStruct, BondStruct, Decimal, Observation, PriceMode } from './sale'
 import { DECIMAL, DENOMINATOR, toDecimal, toScale } from './utils'
 
 export const getPriceAfterSlippage = (price: Decimal, slippage: Decimal) => {
   return price.v.mul(slippage.v.add(DENOMINATOR)).div(DENOMINATOR)
 }
 
-export const calculateSellPrice = (bondSale: BondSaleStruct, amount: BN) => {
+export const getAmountAfterSlippage = (amount: BN, slippage: Decimal) => {
+  return amount.mul(DENOMINATOR.sub(slippage.v)).div(DENOMINATOR)
+}
+
+export const calculateDebt = (bondSale: BondSaleStruct, currentTime: BN) => {
+  const elapsed = currentTime.sub(bondSale.lastDecay)
+  if (elapsed.gte(bondSale.vestingTime)) {
+    return new BN(0)
+  }
+
+  return bondSale.totalDebt.v.sub(bondSale.totalDebt.v.mul(elapsed).div(bondSale.vestingTime))
+}
+
+export const calculateControlVariable = (bondSale: BondSaleStruct, currentTime: BN) => {
+  const controlVariable = bondSale.controlVariable.v
+  const target = bondSale.adjustmentTarget.v
+  if (bondSale.adjustmentBuffer.isZero()) {
+    return controlVariable
+  }
+
+  const steps = currentTime.sub(bondSale.lastAdjustment).div(bondSale.adjustmentBuffer)
+  const change = bondSale.adjustmentRate.v.mul(steps)
+
+  if (controlVariable.lt(target)) {
+    return BN.min(controlVariable.add(change), target)
+  }
+  return BN.max(controlVariable.sub(change), target)
+}
+
+export const calculateDebtPrice = (bondSale: BondSaleStruct, currentTime: BN) => {
+  const debtRatio = calculateDebt(bondSale, currentTime).mul(DENOMINATOR).div(bondSale.supply.v)
+  const price = decimalMul(calculateControlVariable(bondSale, currentTime), debtRatio)
+
+  return BN.max(price, bondSale.floorPrice.v)
+}
+
+// velocity after the controller update that runs before every purchase
+export const calculateVelocity = (bondSale: BondSaleStruct, currentTime: BN) => {
+  const velocity = bondSale.velocity.v
+  if (bondSale.velocityGain.v.isZero()) {
+    return velocity
+  }
+
+  const saleTime = bondSale.endTime.sub(bondSale.startTime)
+  const elapsed = BN.min(BN.max(currentTime, bondSale.startTime).sub(bondSale.startTime), saleTime)
+  const timeFraction = elapsed.mul(DENOMINATOR).div(saleTime)
+  const soldFraction = bondSale.supply.v
+    .sub(bondSale.remainingAmount.v)
+    .mul(DENOMINATOR)
+    .div(bondSale.supply.v)
+
+  if (timeFraction.lt(soldFraction)) {
+    const change = decimalMul(bondSale.velocityGain.v, soldFraction.sub(timeFraction))
+    return velocity.lt(bondSale.minVelocity.v.add(change))
+      ? bondSale.minVelocity.v
+      : velocity.sub(change)
+  }
+  const change = decimalMul(bondSale.velocityGain.v, timeFraction.sub(soldFraction))
+  return BN.min(velocity.add(change), bondSale.maxVelocity.v)
+}
+
+export const calculateVolumeDiscount = (bondSale: BondSaleStruct, amount: BN) => {
+  const tiers = bondSale.volumeTiers.filter(
+    tier => !tier.minAmount.v.isZero() && amount.gte(tier.minAmount.v)
+  )
+
+  return tiers.length === 0 ? new BN(0) : tiers[tiers.length - 1].discount.v
+}
+
+export const applyVolumeDiscount = (bondSale: BondSaleStruct, price: BN, amount: BN) => {
+  return decimalMul(price, DENOMINATOR.sub(calculateVolumeDiscount(bondSale, amount)))
+}
+
+export const applyTermDiscount = (bondSale: BondSaleStruct, price: BN, vestingTerm?: number) => {
+  if (vestingTerm === undefined) {
+    return price
+  }
+
+  return decimalMul(price, DENOMINATOR.sub(bondSale.vestingTerms[vestingTerm].discount.v))
+}
+
+// vestingTerm is the index of the term chosen by the buyer
+export const calculateSellPrice = (bondSale: BondSaleStruct, amount: BN, vestingTerm?: number) => {
   const currentTime = new BN(Math.floor(Date.now() / 1000))
+  if (bondSale.priceMode === PriceMode.Debt) {
+    const debtPrice = calculateDebtPrice(bondSale, currentTime)
+    return applyTermDiscount(
+      bondSale,
+      applyVolumeDiscount(bondSale, debtPrice, amount),
+      vestingTerm
+    )
+  }
+
   const deltaTime = toScale(currentTime.sub(bondSale.lastTrade), DECIMAL)
   const saleTime = toScale(bondSale.endTime.sub(bondSale.startTime), DECIMAL)
   const timeRatio = decimalDiv(deltaTime, saleTime)
 
+  const velocity = calculateVelocity(bondSale, currentTime)
+
   const deltaPrice = decimalMul(
-    decimalMul(decimalMul(bondSale.velocity.v, bondSale.upBound.v), bondSale.floorPrice.v),
+    decimalMul(decimalMul(velocity, bondSale.upBound.v), bondSale.floorPrice.v),
     timeRatio
   )
   const supplyRatio = amount.mul(DENOMINATOR).div(bondSale.supply.v)
@@ -26,26 +119,69 @@ export const calculateSellPrice = (bondSale: BondSaleStruct, amount: BN) => {
   }
 
   const jump = decimalMul(decimalMul(supplyRatio, bondSale.upBound.v), bondSale.floorPrice.v)
+  const sellPrice = price.add(decimalMul(toDecimal(new BN(5), 1).v, jump))
+
+  const cappedPrice = BN.min(sellPrice, getCeilPrice(bondSale.upBound, bondSale.floorPrice))
+  const discountedPrice = applyVolumeDiscount(bondSale, cappedPrice, amount)
+
+  return applyTermDiscount(bondSale, discountedPrice, vestingTerm)
+}
+
+export const calculateBuyAmount = (
+  bondSale: BondSaleStruct,
+  quoteAmount: BN,
+  vestingTerm?: number
+) => {
+  const isAffordable = (amount: BN) => {
+    const sellPrice = calculateSellPrice(bondSale, amount, vestingTerm)
+    const cost = amount.mul(sellPrice).add(DENOMINATOR.subn(1)).div(DENOMINATOR)
+    return cost.lte(quoteAmount)
+  }
+
+  // cost drops where a volume tier starts, so the largest tier affordable at its start is searched
+  const remaining = bondSale.remainingAmount.v
+  const tierStarts = bondSale.volumeTiers
+    .filter(tier => !tier.minAmount.v.isZero())
+    .map(tier => tier.minAmount.v)
+    .reverse()
+  let upper = remaining
+
+  for (const start of [...tierStarts, new BN(0)]) {
+    if (start.gt(remaining) || !isAffordable(start)) {
+      upper = BN.min(upper, BN.max(start.subn(1), new BN(0)))
+      continue
+    }
+
+    let lower = start
+    while (lower.lt(upper)) {
+      const middle = upper.sub(upper.sub(lower).divn(2))
 
-  return price.add(decimalMul(toDecimal(new BN(5), 1).v, jump))
+      if (isAffordable(middle)) {
+        lower = middle
+      } else {
+        upper = middle.subn(1)
+      }
+    }
+    return lower
+  }
+
+  return new BN(0)
 }
 
 export const calculateAmountToClaim = (bond: BondStruct) => {
   const currentTime = new BN(Math.floor(Date.now() / 1000))
 
-  let timeDelta: BN
-  if (currentTime.lt(bond.vestingEnd)) {
-    timeDelta = currentTime.sub(bond.lastClaim)
-  } else {
-    timeDelta = bond.vestingEnd.sub(bond.lastClaim)
+  if (currentTime.gte(bond.vestingEnd)) {
+    return bond.bondAmount.v.sub(bond.claimedAmount.v)
   }
 
+  const timeDelta = currentTime.sub(bond.vestingStart)
   const duration = bond.vestingEnd.sub(bond.vestingStart)
   const fraction = decimalDiv(toDecimal(timeDelta, 0).v, toDecimal(duration, 0).v)
 
-  const amountToClaim = decimalMul(bond.bondAmount.v, fraction)
+  const vestedAmount = decimalMul(bond.bondAmount.v, fraction)
 
-  return amountToClaim
+  return vestedAmount.sub(bond.claimedAmount.v)
 }
 
 export const decimalMul = (one: BN, two: BN) => {
@@ -59,3 +195,12 @@ export const decimalDiv = (one: BN, two: BN) => {
 export const getCeilPrice = (upBound: Decimal, floorPrice: Decimal) => {
   return toDecimal(new BN(1), 0).v.add(upBound.v).mul(floorPrice.v).div(DENOMINATOR)
 }
+
+// time weighted average price between two observations, the on-chain accumulator wraps at u128
+export const calculateAveragePrice = (older: Observation, newer: Observation) => {
+  const elapsed = newer.timestamp.sub(older.timestamp)
+  const delta = newer.priceCumulative.sub(older.priceCumulative)
+  const wrapped = delta.isNeg() ? delta.add(new BN(1).shln(128)) : delta
+
+  return wrapped.div(elapsed)
+}
@@ -1,7 +1,13 @@
 import { Program, Provider, BN } from '@project-serum/anchor'
 import { bs58 } from '@project-serum/anchor/dist/cjs/utils/bytes'
-import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import {
+  AccountLayout,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID
+} from '@solana/spl-token'
+import {
+  AccountMeta,
   Connection,
   Keypair,
   PublicKey,
@@ -18,6 +24,13 @@ import { bigNumberToBuffer, signAndSend } from './utils'
 export const SEED = 'Bonds'
 export const BOND_SEED = 'bondv1'
 export const STATE_SEED = 'statev1'
+export const REFERRAL_SEED = 'referralv1'
+export const FEE_VAULT_SEED = 'feevaultv1'
+export const UNWRAP_SEED = 'unwrapv1'
+export const SALE_QUOTE_SEED = 'salequotev1'
+export const PRICE_HISTORY_SEED = 'pricehistoryv1'
+// price: u128, confidence: u128, publish_time: u64, little endian
+export const PRICE_FEED_SIZE = 40
 
 export const DEFAULT_PUBLIC_KEY = new PublicKey(0)
 
@@ -79,6 +92,119 @@ export class Bonds {
     }
   }
 
+  async getState() {
+    const { stateAddress } = await this.getStateAddress()
+    return (await this.program.account.state.fetch(stateAddress)) as StateStruct
+  }
+
+  async getReferralAddress(bondSale: PublicKey, referrerQuoteAccount: PublicKey) {
+    const [referralAddress, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(REFERRAL_SEED), bondSale.toBuffer(), referrerQuoteAccount.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      referralAddress,
+      bump
+    }
+  }
+
+  async getReferral(bondSale: PublicKey, referrerQuoteAccount: PublicKey) {
+    const { referralAddress } = await this.getReferralAddress(bondSale, referrerQuoteAccount)
+    return (await this.program.account.referral.fetch(referralAddress)) as ReferralStruct
+  }
+
+  async getReferralAccounts(bondSale: PublicKey, referrerQuoteAccount?: PublicKey) {
+    if (referrerQuoteAccount === undefined) {
+      return []
+    }
+    const { referralAddress } = await this.getReferralAddress(bondSale, referrerQuoteAccount)
+
+    return [{ pubkey: referralAddress, isWritable: true, isSigner: false }]
+  }
+
+  async getFeeVaultAddress(tokenQuote: PublicKey) {
+    const [feeVaultAddress, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(FEE_VAULT_SEED), tokenQuote.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      feeVaultAddress,
+      bump
+    }
+  }
+
+  async getFeeVault(tokenQuote: PublicKey) {
+    const { feeVaultAddress } = await this.getFeeVaultAddress(tokenQuote)
+    return (await this.program.account.feeVault.fetch(feeVaultAddress)) as FeeVaultStruct
+  }
+
+  async getUnwrapAddress(bondSale: PublicKey) {
+    const [unwrapAddress, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(UNWRAP_SEED), bondSale.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      unwrapAddress,
+      bump
+    }
+  }
+
+  async getSaleQuoteAddress(bondSale: PublicKey, tokenQuote: PublicKey) {
+    const [saleQuoteAddress, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(SALE_QUOTE_SEED), bondSale.toBuffer(), tokenQuote.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      saleQuoteAddress,
+      bump
+    }
+  }
+
+  async getSaleQuote(bondSale: PublicKey, tokenQuote: PublicKey) {
+    const { saleQuoteAddress } = await this.getSaleQuoteAddress(bondSale, tokenQuote)
+    return (await this.program.account.saleQuote.fetch(saleQuoteAddress)) as SaleQuoteStruct
+  }
+
+  async getAllSaleQuotes(bondSale: PublicKey) {
+    return await this.program.account.saleQuote.all([
+      {
+        memcmp: { bytes: bs58.encode(bondSale.toBuffer()), offset: 8 }
+      }
+    ])
+  }
+
+  async getPriceHistoryAddress(bondSale: PublicKey) {
+    const [priceHistoryAddress, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(PRICE_HISTORY_SEED), bondSale.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      priceHistoryAddress,
+      bump
+    }
+  }
+
+  async getPriceHistory(bondSale: PublicKey) {
+    const { priceHistoryAddress } = await this.getPriceHistoryAddress(bondSale)
+    return (await this.program.account.priceHistory.fetch(
+      priceHistoryAddress
+    )) as PriceHistoryStruct
+  }
+
+  async getPriceHistoryAccounts(bondSale: PublicKey, withPriceHistory?: boolean) {
+    if (!(withPriceHistory ?? false)) {
+      return []
+    }
+    const { priceHistoryAddress } = await this.getPriceHistoryAddress(bondSale)
+
+    return [{ pubkey: priceHistoryAddress, isWritable: true, isSigner: false }]
+  }
+
   async createStateInstruction(admin: PublicKey) {
     const { stateAddress } = await this.getStateAddress()
     const { programAuthority, nonce } = await this.getProgramAuthority()
@@ -109,6 +235,48 @@ export class Bonds {
     }
   }
 
+  async createFeeVaultInstruction(createFeeVault: CreateFeeVault, feeVaultTokenAccount: PublicKey) {
+    const { tokenQuote } = createFeeVault
+    const payer = createFeeVault.payer ?? this.wallet.publicKey
+    const { stateAddress } = await this.getStateAddress()
+    const { programAuthority } = await this.getProgramAuthority()
+    const { feeVaultAddress } = await this.getFeeVaultAddress(tokenQuote)
+
+    return this.program.instruction.createFeeVault({
+      accounts: {
+        state: stateAddress,
+        feeVault: feeVaultAddress,
+        tokenQuote,
+        feeVaultTokenAccount,
+        payer,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      }
+    })
+  }
+
+  async createFeeVaultTransaction(createFeeVault: CreateFeeVault, feeVaultTokenAccount: PublicKey) {
+    const ix = await this.createFeeVaultInstruction(createFeeVault, feeVaultTokenAccount)
+
+    return new Transaction().add(ix)
+  }
+
+  async createFeeVault(createFeeVault: CreateFeeVault, signer?: Keypair) {
+    const feeVaultTokenAccount = Keypair.generate()
+    const tx = await this.createFeeVaultTransaction(createFeeVault, feeVaultTokenAccount.publicKey)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, [feeVaultTokenAccount], this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer, feeVaultTokenAccount])
+    }
+
+    const { feeVaultAddress } = await this.getFeeVaultAddress(createFeeVault.tokenQuote)
+    return feeVaultAddress
+  }
+
   async initBondSaleInstruction(
     initBondSale: InitBondSale,
     bondSalePub: PublicKey,
@@ -119,6 +287,7 @@ export class Bonds {
       tokenBond,
       tokenQuote,
       payerBondAccount,
+      proceedsDestination,
       floorPrice,
       upBound,
       velocity,
@@ -127,6 +296,11 @@ export class Bonds {
       vestingTime
     } = initBondSale
     const payerPubkey = initBondSale.payer ?? this.wallet.publicKey
+    const softCap = initBondSale.softCap ?? new BN(0)
+    const hardCap = initBondSale.hardCap ?? new BN(0)
+    const proceedsVestingTime = initBondSale.proceedsVestingTime ?? new BN(0)
+    const proceedsSplits = initBondSale.proceedsSplits ?? []
+    const mintOnDemand = initBondSale.mintOnDemand ?? false
     const { stateAddress } = await this.getStateAddress()
 
     const { programAuthority } = await this.getProgramAuthority()
@@ -138,6 +312,11 @@ export class Bonds {
       supply,
       duration,
       vestingTime,
+      softCap,
+      hardCap,
+      proceedsVestingTime,
+      proceedsSplits,
+      mintOnDemand,
       {
         accounts: {
           state: stateAddress,
@@ -147,6 +326,7 @@ export class Bonds {
           tokenBondAccount: tokenBondAccountPub,
           tokenQuoteAccount: tokenQuoteAccountPub,
           payerBondAccount,
+          proceedsDestination,
           payer: payerPubkey,
           authority: programAuthority,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -278,54 +458,136 @@ export class Bonds {
   }
 
   async createBondInstruction(createBond: CreateBond, bondPub: PublicKey) {
-    const { bondSale, ownerQuoteAccount, amount, priceLimit } = createBond
+    const { bondSale, amount, priceLimit } = createBond
     const ownerPubkey = createBond.owner ?? this.wallet.publicKey
+    const ownerQuoteAccount = createBond.ownerQuoteAccount ?? ownerPubkey
+    const recipient = createBond.recipient ?? ownerPubkey
+    const payer = createBond.payer ?? ownerPubkey
+    const expiresAt = createBond.expiresAt ?? null
+    const vestingTerm = createBond.vestingTerm ?? null
     const bondSaleStruct = await this.getBondSale(bondSale)
+    const { stateAddress } = await this.getStateAddress()
+    const { programAuthority } = await this.getProgramAuthority()
+    const { feeVaultAddress } = await this.getFeeVaultAddress(bondSaleStruct.tokenQuote)
+    const feeVault = await this.getFeeVault(bondSaleStruct.tokenQuote)
+    const remainingAccounts = await this.getReferralAccounts(
+      bondSale,
+      createBond.referrerQuoteAccount
+    )
 
-    return this.program.instruction.createBond(amount, priceLimit, {
+    return this.program.instruction.createBond(amount, priceLimit, expiresAt, vestingTerm, {
       accounts: {
+        state: stateAddress,
         bondSale,
         bond: bondPub,
         ownerQuoteAccount,
         tokenBondAccount: bondSaleStruct.tokenBondAccount,
         tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
+        feeVault: feeVaultAddress,
+        feeVaultTokenAccount: feeVault.tokenAccount,
         owner: ownerPubkey,
+        oracle: bondSaleStruct.oracle,
+        recipient,
+        payer,
+        authority: programAuthority,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY
-      }
+      },
+      remainingAccounts
     })
   }
 
   async createBondTransaction(createBond: CreateBond, bondPub: PublicKey) {
-    const payer = createBond.owner ?? this.wallet.publicKey
-
-    const createIx = SystemProgram.createAccount({
-      fromPubkey: payer,
-      newAccountPubkey: bondPub,
-      space: this.program.account.bond.size,
-      lamports: await this.connection.getMinimumBalanceForRentExemption(
-        this.program.account.bond.size
-      ),
-      programId: this.program.programId
-    })
-    const initIx = await this.createBondInstruction(createBond, bondPub)
+    const payer = createBond.payer ?? createBond.owner ?? this.wallet.publicKey
+    const ix = await this.createBondInstruction(createBond, bondPub)
 
     return new Transaction({
       feePayer: payer
-    })
-      .add(createIx)
-      .add(initIx)
+    }).add(ix)
   }
 
-  async createBond(createBond: CreateBond, signer?: Keypair) {
+  async createBond(createBond: CreateBond, signer?: Keypair, payer?: Keypair) {
     const bond = Keypair.generate()
     const tx = await this.createBondTransaction(createBond, bond.publicKey)
+    // first signer pays the transaction fee
+    const payers = payer === undefined ? [] : [payer]
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, [...payers, bond], this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [...payers, signer, bond])
+    }
+
+    return bond.publicKey
+  }
+
+  async createBondExactInInstruction(createBondExactIn: CreateBondExactIn, bondPub: PublicKey) {
+    const { bondSale, quoteAmount, minBondOut } = createBondExactIn
+    const ownerPubkey = createBondExactIn.owner ?? this.wallet.publicKey
+    const ownerQuoteAccount = createBondExactIn.ownerQuoteAccount ?? ownerPubkey
+    const recipient = createBondExactIn.recipient ?? ownerPubkey
+    const payer = createBondExactIn.payer ?? ownerPubkey
+    const expiresAt = createBondExactIn.expiresAt ?? null
+    const vestingTerm = createBondExactIn.vestingTerm ?? null
+    const bondSaleStruct = await this.getBondSale(bondSale)
+    const { stateAddress } = await this.getStateAddress()
+    const { programAuthority } = await this.getProgramAuthority()
+    const { feeVaultAddress } = await this.getFeeVaultAddress(bondSaleStruct.tokenQuote)
+    const feeVault = await this.getFeeVault(bondSaleStruct.tokenQuote)
+    const remainingAccounts = await this.getReferralAccounts(
+      bondSale,
+      createBondExactIn.referrerQuoteAccount
+    )
+
+    return this.program.instruction.createBondExactIn(
+      quoteAmount,
+      minBondOut,
+      expiresAt,
+      vestingTerm,
+      {
+        accounts: {
+          state: stateAddress,
+          bondSale,
+          bond: bondPub,
+          ownerQuoteAccount,
+          tokenBondAccount: bondSaleStruct.tokenBondAccount,
+          tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
+          feeVault: feeVaultAddress,
+          feeVaultTokenAccount: feeVault.tokenAccount,
+          owner: ownerPubkey,
+          oracle: bondSaleStruct.oracle,
+          recipient,
+          payer,
+          authority: programAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY
+        },
+        remainingAccounts
+      }
+    )
+  }
+
+  async createBondExactInTransaction(createBondExactIn: CreateBondExactIn, bondPub: PublicKey) {
+    const payer = createBondExactIn.payer ?? createBondExactIn.owner ?? this.wallet.publicKey
+    const ix = await this.createBondExactInInstruction(createBondExactIn, bondPub)
+
+    return new Transaction({
+      feePayer: payer
+    }).add(ix)
+  }
+
+  async createBondExactIn(createBondExactIn: CreateBondExactIn, signer?: Keypair, payer?: Keypair) {
+    const bond = Keypair.generate()
+    const tx = await this.createBondExactInTransaction(createBondExactIn, bond.publicKey)
+    // first signer pays the transaction fee
+    const payers = payer === undefined ? [] : [payer]
 
     if (signer === undefined) {
-      await signAndSend(tx, this.connection, [bond], this.wallet)
+      await signAndSend(tx, this.connection, [...payers, bond], this.wallet)
     } else {
-      await signAndSend(tx, this.connection, [signer, bond])
+      await signAndSend(tx, this.connection, [...payers, signer, bond])
     }
 
     return bond.publicKey
@@ -387,30 +649,79 @@ export class Bonds {
     }
   }
 
+  getSplitRecipients(proceedsSplits: ProceedsSplit[]) {
+    return proceedsSplits
+      .filter(split => split.bps !== 0)
+      .map(split => ({ pubkey: split.recipient, isWritable: true, isSigner: false }))
+  }
+
+  // each split is followed by the associated token account of its owner for the sale quote mint
+  async getSaleQuoteSplitRecipients(proceedsSplits: ProceedsSplit[], tokenQuote: PublicKey) {
+    const remainingAccounts: AccountMeta[] = []
+    for (const recipient of this.getSplitRecipients(proceedsSplits)) {
+      const recipientInfo = await this.connection.getAccountInfo(recipient.pubkey)
+      const owner = new PublicKey(AccountLayout.decode(recipientInfo?.data).owner)
+      const destination = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        tokenQuote,
+        owner
+      )
+
+      remainingAccounts.push(
+        { ...recipient, isWritable: false },
+        { pubkey: destination, isWritable: true, isSigner: false }
+      )
+    }
+    return remainingAccounts
+  }
+
   async claimQuoteInstruction(claimQuote: ClaimQuote) {
-    const { bondSale, payerQuoteAccount } = claimQuote
+    const { bondSale } = claimQuote
     const payerPubkey = claimQuote.payer ?? this.wallet.publicKey
-    const bondSaleQuoteAccount = (await this.getBondSale(bondSale)).tokenQuoteAccount
+    const {
+      tokenQuote,
+      tokenQuoteAccount: bondSaleQuoteAccount,
+      proceedsDestination,
+      proceedsSplits
+    } = await this.getBondSale(bondSale)
     const { programAuthority } = await this.getProgramAuthority()
     const { stateAddress } = await this.getStateAddress()
+    const { unwrapAddress } = await this.getUnwrapAddress(bondSale)
 
     return this.program.instruction.claimQuote({
       accounts: {
         state: stateAddress,
         bondSale: bondSale,
         bondSaleQuoteAccount,
-        payerQuoteAccount,
+        proceedsDestination,
+        tokenQuote,
+        unwrapAccount: unwrapAddress,
         payer: payerPubkey,
         authority: programAuthority,
-        tokenProgram: TOKEN_PROGRAM_ID
-      }
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      },
+      remainingAccounts: this.getSplitRecipients(proceedsSplits)
     })
   }
 
+  // proceeds of every sale quote are claimed along with the main quote token
   async claimQuoteTransaction(claimQuote: ClaimQuote) {
     const ix = await this.claimQuoteInstruction(claimQuote)
+    const saleQuotes = await this.getAllSaleQuotes(claimQuote.bondSale)
+    const saleQuoteIxs = await Promise.all(
+      saleQuotes.map(({ account }) =>
+        this.claimSaleQuoteInstruction({
+          bondSale: claimQuote.bondSale,
+          tokenQuote: account.tokenQuote,
+          payer: claimQuote.payer
+        })
+      )
+    )
 
-    return new Transaction().add(ix)
+    return new Transaction().add(ix, ...saleQuoteIxs)
   }
 
   async claimQuote(claimQuote: ClaimQuote, signer?: Keypair) {
@@ -423,22 +734,71 @@ export class Bonds {
     }
   }
 
+  async refundBondInstruction(refundBond: RefundBond) {
+    const { bondSale, bondId } = refundBond
+    const owner = refundBond.owner ?? this.wallet.publicKey
+    const ownerQuoteAccount = refundBond.ownerQuoteAccount ?? owner
+    const bond = await this.getBondById(bondSale, bondId)
+    const { programAuthority } = await this.getProgramAuthority()
+    const { stateAddress } = await this.getStateAddress()
+    const { unwrapAddress } = await this.getUnwrapAddress(bondSale)
+    const bondSaleStruct = await this.getBondSale(bondSale)
+
+    return this.program.instruction.refundBond({
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        bond: bond.publicKey,
+        tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
+        ownerQuoteAccount,
+        owner,
+        rentPayer: bond.account.rentPayer,
+        tokenQuote: bondSaleStruct.tokenQuote,
+        unwrapAccount: unwrapAddress,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      }
+    })
+  }
+
+  async refundBondTransaction(refundBond: RefundBond) {
+    const ix = await this.refundBondInstruction(refundBond)
+
+    return new Transaction().add(ix)
+  }
+
+  async refundBond(refundBond: RefundBond, signer?: Keypair) {
+    const tx = await this.refundBondTransaction(refundBond)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
   async claimBondInstruction(claimBond: ClaimBond) {
     const { bondSale, ownerBondAccount, bondId } = claimBond
     const owner = claimBond.owner ?? this.wallet.publicKey
+    const recipientBondAccount = claimBond.recipientBondAccount ?? ownerBondAccount
+    const amount = claimBond.amount ?? null
     const bond = await this.getBondById(bondSale, bondId)
     const { programAuthority } = await this.getProgramAuthority()
     const { stateAddress } = await this.getStateAddress()
     const bondSaleStruct = await this.getBondSale(bondSale)
 
-    return this.program.instruction.claimBond({
+    return this.program.instruction.claimBond(amount, {
       accounts: {
         state: stateAddress,
         bondSale,
         bond: bond.publicKey,
         tokenBondAccount: bondSaleStruct.tokenBondAccount,
-        ownerBondAccount,
+        tokenBond: bondSaleStruct.tokenBond,
+        recipientBondAccount,
         owner,
+        rentPayer: bond.account.rentPayer,
         authority: programAuthority,
         tokenProgram: TOKEN_PROGRAM_ID
       }
@@ -461,37 +821,28 @@ export class Bonds {
     }
   }
 
-  async endBondSaleInstruction(endBondSale: EndBondSale) {
-    const { bondSale, payerQuoteAccount, payerBondAccount, adminQuoteAccount } = endBondSale
-    const { programAuthority } = await this.getProgramAuthority()
-    const bondSaleStruct = await this.getBondSale(bondSale)
-    const payerPubkey = endBondSale.payer ?? this.wallet.publicKey
-    const { stateAddress } = await this.getStateAddress()
+  async closeBondInstruction(closeBond: CloseBond) {
+    const { bondSale, bondId } = closeBond
+    const owner = closeBond.owner ?? this.wallet.publicKey
+    const bond = await this.getBondById(bondSale, bondId)
 
-    return this.program.instruction.endBondSale({
+    return this.program.instruction.closeBond({
       accounts: {
-        state: stateAddress,
-        bondSale,
-        tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
-        tokenBondAccount: bondSaleStruct.tokenBondAccount,
-        payerQuoteAccount,
-        payerBondAccount,
-        adminQuoteAccount,
-        authority: programAuthority,
-        payer: payerPubkey,
-        tokenProgram: TOKEN_PROGRAM_ID
+        bond: bond.publicKey,
+        owner,
+        rentPayer: bond.account.rentPayer
       }
     })
   }
 
-  async endBondSaleTransaction(endBondSale: EndBondSale) {
-    const ix = await this.endBondSaleInstruction(endBondSale)
+  async closeBondTransaction(closeBond: CloseBond) {
+    const ix = await this.closeBondInstruction(closeBond)
 
     return new Transaction().add(ix)
   }
 
-  async endBondSale(endBondSale: EndBondSale, signer?: Keypair) {
-    const tx = await this.endBondSaleTransaction(endBondSale)
+  async closeBond(closeBond: CloseBond, signer?: Keypair) {
+    const tx = await this.closeBondTransaction(closeBond)
 
     if (signer === undefined) {
       await signAndSend(tx, this.connection, undefined, this.wallet)
@@ -500,28 +851,28 @@ export class Bonds {
     }
   }
 
-  async changeFeeInstruction(changeFee: ChangeFee) {
-    const { bondSale, newFee } = changeFee
-    const { stateAddress } = await this.getStateAddress()
-    const admin = changeFee.admin ?? this.wallet.publicKey
+  async setBondDelegateInstruction(setBondDelegate: SetBondDelegate) {
+    const { bondSale, bondId, delegate } = setBondDelegate
+    const owner = setBondDelegate.owner ?? this.wallet.publicKey
+    const tip = setBondDelegate.tip ?? new BN(0)
+    const bond = await this.getBondById(bondSale, bondId)
 
-    return this.program.instruction.changeFee(newFee, {
+    return this.program.instruction.setBondDelegate(delegate, tip, {
       accounts: {
-        state: stateAddress,
-        bondSale,
-        admin
+        bond: bond.publicKey,
+        owner
       }
     })
   }
 
-  async changeFeeTransaction(changeFee: ChangeFee) {
-    const ix = await this.changeFeeInstruction(changeFee)
+  async setBondDelegateTransaction(setBondDelegate: SetBondDelegate) {
+    const ix = await this.setBondDelegateInstruction(setBondDelegate)
 
     return new Transaction().add(ix)
   }
 
-  async changeFee(changeFee: ChangeFee, signer?: Keypair) {
-    const tx = await this.changeFeeTransaction(changeFee)
+  async setBondDelegate(setBondDelegate: SetBondDelegate, signer?: Keypair) {
+    const tx = await this.setBondDelegateTransaction(setBondDelegate)
 
     if (signer === undefined) {
       await signAndSend(tx, this.connection, undefined, this.wallet)
@@ -530,34 +881,40 @@ export class Bonds {
     }
   }
 
-  async withdrawFeeInstruction(withdrawFee: WithdrawFee) {
-    const { bondSale, adminQuoteAccount } = withdrawFee
+  async claimBondDelegatedInstruction(claimBondDelegated: ClaimBondDelegated) {
+    const { bondSale, ownerBondAccount, delegateBondAccount, bondId } = claimBondDelegated
+    const delegate = claimBondDelegated.delegate ?? this.wallet.publicKey
+    const bond = await this.getBondById(bondSale, bondId)
+    const { programAuthority } = await this.getProgramAuthority()
     const { stateAddress } = await this.getStateAddress()
-    const admin = withdrawFee.admin ?? this.wallet.publicKey
     const bondSaleStruct = await this.getBondSale(bondSale)
-    const { programAuthority } = await this.getProgramAuthority()
 
-    return this.program.instruction.withdrawFee({
+    return this.program.instruction.claimBondDelegated({
       accounts: {
         state: stateAddress,
         bondSale,
-        tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
-        adminQuoteAccount,
-        admin,
+        bond: bond.publicKey,
+        tokenBondAccount: bondSaleStruct.tokenBondAccount,
+        tokenBond: bondSaleStruct.tokenBond,
+        ownerBondAccount,
+        delegateBondAccount,
+        owner: bond.account.owner,
+        delegate,
+        rentPayer: bond.account.rentPayer,
         authority: programAuthority,
         tokenProgram: TOKEN_PROGRAM_ID
       }
     })
   }
 
-  async withdrawFeeTransaction(withdrawFee: WithdrawFee) {
-    const ix = await this.withdrawFeeInstruction(withdrawFee)
+  async claimBondDelegatedTransaction(claimBondDelegated: ClaimBondDelegated) {
+    const ix = await this.claimBondDelegatedInstruction(claimBondDelegated)
 
     return new Transaction().add(ix)
   }
 
-  async withdrawFee(withdrawFee: WithdrawFee, signer?: Keypair) {
-    const tx = await this.withdrawFeeTransaction(withdrawFee)
+  async claimBondDelegated(claimBondDelegated: ClaimBondDelegated, signer?: Keypair) {
+    const tx = await this.claimBondDelegatedTransaction(claimBondDelegated)
 
     if (signer === undefined) {
       await signAndSend(tx, this.connection, undefined, this.wallet)
@@ -565,27 +922,770 @@ export class Bonds {
       await signAndSend(tx, this.connection, [signer])
     }
   }
-}
 
-export interface InitBondSale {
-  tokenBond: Token
-  tokenQuote: Token
-  payerBondAccount: PublicKey
-  payer?: PublicKey
-  floorPrice: BN
-  upBound: BN
-  velocity: BN
-  supply: BN
-  duration: BN
-  vestingTime: BN
+  async endBondSaleInstruction(endBondSale: EndBondSale) {
+    const { bondSale, payerBondAccount } = endBondSale
+    const { programAuthority } = await this.getProgramAuthority()
+    const bondSaleStruct = await this.getBondSale(bondSale)
+    const payerPubkey = endBondSale.payer ?? this.wallet.publicKey
+    const { stateAddress } = await this.getStateAddress()
+    const { unwrapAddress } = await this.getUnwrapAddress(bondSale)
+
+    return this.program.instruction.endBondSale({
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
+        tokenBondAccount: bondSaleStruct.tokenBondAccount,
+        tokenBond: bondSaleStruct.tokenBond,
+        proceedsDestination: bondSaleStruct.proceedsDestination,
+        tokenQuote: bondSaleStruct.tokenQuote,
+        unwrapAccount: unwrapAddress,
+        payerBondAccount,
+        authority: programAuthority,
+        payer: payerPubkey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      },
+      remainingAccounts: this.getSplitRecipients(bondSaleStruct.proceedsSplits)
+    })
+  }
+
+  async endBondSaleTransaction(endBondSale: EndBondSale) {
+    const ix = await this.endBondSaleInstruction(endBondSale)
+
+    return new Transaction().add(ix)
+  }
+
+  async endBondSale(endBondSale: EndBondSale, signer?: Keypair) {
+    const tx = await this.endBondSaleTransaction(endBondSale)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async changeFeeInstruction(changeFee: ChangeFee) {
+    const { bondSale, newFee } = changeFee
+    const { stateAddress } = await this.getStateAddress()
+    const admin = changeFee.admin ?? this.wallet.publicKey
+
+    return this.program.instruction.changeFee(newFee, {
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        admin
+      }
+    })
+  }
+
+  async changeFeeTransaction(changeFee: ChangeFee) {
+    const ix = await this.changeFeeInstruction(changeFee)
+
+    return new Transaction().add(ix)
+  }
+
+  async changeFee(changeFee: ChangeFee, signer?: Keypair) {
+    const tx = await this.changeFeeTransaction(changeFee)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async changeFeeModeInstruction(changeFeeMode: ChangeFeeMode) {
+    const { bondSale, feeMode, bondFee } = changeFeeMode
+    const { stateAddress } = await this.getStateAddress()
+    const admin = changeFeeMode.admin ?? this.wallet.publicKey
+
+    return this.program.instruction.changeFeeMode(feeMode, bondFee, {
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        admin
+      }
+    })
+  }
+
+  async changeFeeModeTransaction(changeFeeMode: ChangeFeeMode) {
+    const ix = await this.changeFeeModeInstruction(changeFeeMode)
+
+    return new Transaction().add(ix)
+  }
+
+  async changeFeeMode(changeFeeMode: ChangeFeeMode, signer?: Keypair) {
+    const tx = await this.changeFeeModeTransaction(changeFeeMode)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async withdrawFeeInstruction(withdrawFee: WithdrawFee) {
+    const { tokenQuote, adminQuoteAccount } = withdrawFee
+    const { stateAddress } = await this.getStateAddress()
+    const admin = withdrawFee.admin ?? this.wallet.publicKey
+    const { feeVaultAddress } = await this.getFeeVaultAddress(tokenQuote)
+    const feeVault = await this.getFeeVault(tokenQuote)
+    const { programAuthority } = await this.getProgramAuthority()
+
+    return this.program.instruction.withdrawFee({
+      accounts: {
+        state: stateAddress,
+        feeVault: feeVaultAddress,
+        feeVaultTokenAccount: feeVault.tokenAccount,
+        adminQuoteAccount,
+        admin,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  async withdrawFeeTransaction(withdrawFee: WithdrawFee) {
+    const ix = await this.withdrawFeeInstruction(withdrawFee)
+
+    return new Transaction().add(ix)
+  }
+
+  async withdrawFee(withdrawFee: WithdrawFee, signer?: Keypair) {
+    const tx = await this.withdrawFeeTransaction(withdrawFee)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async withdrawBondFeeInstruction(withdrawBondFee: WithdrawBondFee) {
+    const { bondSale, adminBondAccount } = withdrawBondFee
+    const { stateAddress } = await this.getStateAddress()
+    const admin = withdrawBondFee.admin ?? this.wallet.publicKey
+    const bondSaleStruct = await this.getBondSale(bondSale)
+    const { programAuthority } = await this.getProgramAuthority()
+
+    return this.program.instruction.withdrawBondFee({
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        tokenBondAccount: bondSaleStruct.tokenBondAccount,
+        adminBondAccount,
+        admin,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  async withdrawBondFeeTransaction(withdrawBondFee: WithdrawBondFee) {
+    const ix = await this.withdrawBondFeeInstruction(withdrawBondFee)
+
+    return new Transaction().add(ix)
+  }
+
+  async withdrawBondFee(withdrawBondFee: WithdrawBondFee, signer?: Keypair) {
+    const tx = await this.withdrawBondFeeTransaction(withdrawBondFee)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async changeReferralFeeInstruction(changeReferralFee: ChangeReferralFee) {
+    const { bondSale, referralFee } = changeReferralFee
+    const { stateAddress } = await this.getStateAddress()
+    const admin = changeReferralFee.admin ?? this.wallet.publicKey
+
+    return this.program.instruction.changeReferralFee(referralFee, {
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        admin
+      }
+    })
+  }
+
+  async changeReferralFeeTransaction(changeReferralFee: ChangeReferralFee) {
+    const ix = await this.changeReferralFeeInstruction(changeReferralFee)
+
+    return new Transaction().add(ix)
+  }
+
+  async changeReferralFee(changeReferralFee: ChangeReferralFee, signer?: Keypair) {
+    const tx = await this.changeReferralFeeTransaction(changeReferralFee)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async createReferralInstruction(createReferral: CreateReferral) {
+    const { bondSale, referrerQuoteAccount } = createReferral
+    const payer = createReferral.payer ?? this.wallet.publicKey
+    const { referralAddress } = await this.getReferralAddress(bondSale, referrerQuoteAccount)
+
+    return this.program.instruction.createReferral({
+      accounts: {
+        bondSale,
+        referral: referralAddress,
+        referrerQuoteAccount,
+        payer,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createReferralTransaction(createReferral: CreateReferral) {
+    const ix = await this.createReferralInstruction(createReferral)
+
+    return new Transaction().add(ix)
+  }
+
+  async createReferral(createReferral: CreateReferral, signer?: Keypair) {
+    const tx = await this.createReferralTransaction(createReferral)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+
+    const { referralAddress } = await this.getReferralAddress(
+      createReferral.bondSale,
+      createReferral.referrerQuoteAccount
+    )
+    return referralAddress
+  }
+
+  async claimReferralInstruction(claimReferral: ClaimReferral) {
+    const { bondSale, referrerQuoteAccount } = claimReferral
+    const referrer = claimReferral.referrer ?? this.wallet.publicKey
+    const { stateAddress } = await this.getStateAddress()
+    const { programAuthority } = await this.getProgramAuthority()
+    const { referralAddress } = await this.getReferralAddress(bondSale, referrerQuoteAccount)
+    const bondSaleStruct = await this.getBondSale(bondSale)
+
+    return this.program.instruction.claimReferral({
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        referral: referralAddress,
+        tokenQuoteAccount: bondSaleStruct.tokenQuoteAccount,
+        referrerQuoteAccount,
+        referrer,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  async claimReferralTransaction(claimReferral: ClaimReferral) {
+    const ix = await this.claimReferralInstruction(claimReferral)
+
+    return new Transaction().add(ix)
+  }
+
+  async claimReferral(claimReferral: ClaimReferral, signer?: Keypair) {
+    const tx = await this.claimReferralTransaction(claimReferral)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async addSaleQuoteInstruction(addSaleQuote: AddSaleQuote, tokenQuoteAccount: PublicKey) {
+    const { bondSale, tokenQuote, proceedsDestination, rate } = addSaleQuote
+    const payer = addSaleQuote.payer ?? this.wallet.publicKey
+    const { stateAddress } = await this.getStateAddress()
+    const { programAuthority } = await this.getProgramAuthority()
+    const { saleQuoteAddress } = await this.getSaleQuoteAddress(bondSale, tokenQuote)
+    const bondSaleStruct = await this.getBondSale(bondSale)
+
+    return this.program.instruction.addSaleQuote(rate, {
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        saleQuote: saleQuoteAddress,
+        tokenQuote,
+        tokenQuoteAccount,
+        bondSaleProceedsDestination: bondSaleStruct.proceedsDestination,
+        proceedsDestination,
+        payer,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      }
+    })
+  }
+
+  async addSaleQuoteTransaction(addSaleQuote: AddSaleQuote, tokenQuoteAccount: PublicKey) {
+    const ix = await this.addSaleQuoteInstruction(addSaleQuote, tokenQuoteAccount)
+
+    return new Transaction().add(ix)
+  }
+
+  async addSaleQuote(addSaleQuote: AddSaleQuote, signer?: Keypair) {
+    const tokenQuoteAccount = Keypair.generate()
+    const tx = await this.addSaleQuoteTransaction(addSaleQuote, tokenQuoteAccount.publicKey)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, [tokenQuoteAccount], this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer, tokenQuoteAccount])
+    }
+
+    const { saleQuoteAddress } = await this.getSaleQuoteAddress(
+      addSaleQuote.bondSale,
+      addSaleQuote.tokenQuote
+    )
+    return saleQuoteAddress
+  }
+
+  async createBondWithSaleQuoteInstruction(
+    createBondWithSaleQuote: CreateBondWithSaleQuote,
+    bondPub: PublicKey
+  ) {
+    const { bondSale, tokenQuote, ownerQuoteAccount, amount, priceLimit } = createBondWithSaleQuote
+    const ownerPubkey = createBondWithSaleQuote.owner ?? this.wallet.publicKey
+    const recipient = createBondWithSaleQuote.recipient ?? ownerPubkey
+    const payer = createBondWithSaleQuote.payer ?? ownerPubkey
+    const expiresAt = createBondWithSaleQuote.expiresAt ?? null
+    const vestingTerm = createBondWithSaleQuote.vestingTerm ?? null
+    const bondSaleStruct = await this.getBondSale(bondSale)
+    const { stateAddress } = await this.getStateAddress()
+    const { saleQuoteAddress } = await this.getSaleQuoteAddress(bondSale, tokenQuote)
+    const saleQuote = await this.getSaleQuote(bondSale, tokenQuote)
+    const { feeVaultAddress } = await this.getFeeVaultAddress(tokenQuote)
+    const feeVault = await this.getFeeVault(tokenQuote)
+
+    return this.program.instruction.createBondWithSaleQuote(
+      amount,
+      priceLimit,
+      expiresAt,
+      vestingTerm,
+      {
+        accounts: {
+          state: stateAddress,
+          bondSale,
+          saleQuote: saleQuoteAddress,
+          bond: bondPub,
+          ownerQuoteAccount,
+          tokenQuoteAccount: saleQuote.tokenQuoteAccount,
+          feeVault: feeVaultAddress,
+          feeVaultTokenAccount: feeVault.tokenAccount,
+          owner: ownerPubkey,
+          oracle: bondSaleStruct.oracle,
+          recipient,
+          payer,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY
+        }
+      }
+    )
+  }
+
+  async createBondWithSaleQuoteTransaction(
+    createBondWithSaleQuote: CreateBondWithSaleQuote,
+    bondPub: PublicKey
+  ) {
+    const payer =
+      createBondWithSaleQuote.payer ?? createBondWithSaleQuote.owner ?? this.wallet.publicKey
+    const ix = await this.createBondWithSaleQuoteInstruction(createBondWithSaleQuote, bondPub)
+
+    return new Transaction({
+      feePayer: payer
+    }).add(ix)
+  }
+
+  async createBondWithSaleQuote(
+    createBondWithSaleQuote: CreateBondWithSaleQuote,
+    signer?: Keypair,
+    payer?: Keypair
+  ) {
+    const bond = Keypair.generate()
+    const tx = await this.createBondWithSaleQuoteTransaction(
+      createBondWithSaleQuote,
+      bond.publicKey
+    )
+    // first signer pays the transaction fee
+    const payers = payer === undefined ? [] : [payer]
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, [...payers, bond], this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [...payers, signer, bond])
+    }
+
+    return bond.publicKey
+  }
+
+  async changePriceModeInstruction(changePriceMode: ChangePriceMode) {
+    const { bondSale, priceMode } = changePriceMode
+    const payer = changePriceMode.payer ?? this.wallet.publicKey
+    const oracle = changePriceMode.oracle ?? DEFAULT_PUBLIC_KEY
+    const discount = changePriceMode.discount ?? new BN(0)
+    const maxStaleness = changePriceMode.maxStaleness ?? new BN(0)
+    const maxConfidence = changePriceMode.maxConfidence ?? new BN(0)
+    const oracleProgram = changePriceMode.oracleProgram ?? DEFAULT_PUBLIC_KEY
+
+    return this.program.instruction.changePriceMode(
+      priceMode,
+      discount,
+      maxStaleness,
+      maxConfidence,
+      oracleProgram,
+      {
+        accounts: {
+          bondSale,
+          oracle,
+          payer
+        }
+      }
+    )
+  }
+
+  async changePriceModeTransaction(changePriceMode: ChangePriceMode) {
+    const ix = await this.changePriceModeInstruction(changePriceMode)
+
+    return new Transaction().add(ix)
+  }
+
+  async changePriceMode(changePriceMode: ChangePriceMode, signer?: Keypair) {
+    const tx = await this.changePriceModeTransaction(changePriceMode)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async setDebtPricingInstruction(setDebtPricing: SetDebtPricing) {
+    const { bondSale, controlVariable } = setDebtPricing
+    const payer = setDebtPricing.payer ?? this.wallet.publicKey
+    const maxPayout = setDebtPricing.maxPayout ?? new BN(0)
+    const maxDebt = setDebtPricing.maxDebt ?? new BN(0)
+    const adjustmentTarget = setDebtPricing.adjustmentTarget ?? controlVariable
+    const adjustmentRate = setDebtPricing.adjustmentRate ?? new BN(0)
+    const adjustmentBuffer = setDebtPricing.adjustmentBuffer ?? new BN(0)
+
+    return this.program.instruction.setDebtPricing(
+      controlVariable,
+      maxPayout,
+      maxDebt,
+      adjustmentTarget,
+      adjustmentRate,
+      adjustmentBuffer,
+      {
+        accounts: {
+          bondSale,
+          payer
+        }
+      }
+    )
+  }
+
+  async setDebtPricingTransaction(setDebtPricing: SetDebtPricing) {
+    const ix = await this.setDebtPricingInstruction(setDebtPricing)
+
+    return new Transaction().add(ix)
+  }
+
+  async setDebtPricing(setDebtPricing: SetDebtPricing, signer?: Keypair) {
+    const tx = await this.setDebtPricingTransaction(setDebtPricing)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async setVelocityControllerInstruction(setVelocityController: SetVelocityController) {
+    const { bondSale, gain, minVelocity, maxVelocity } = setVelocityController
+    const payer = setVelocityController.payer ?? this.wallet.publicKey
+
+    return this.program.instruction.setVelocityController(gain, minVelocity, maxVelocity, {
+      accounts: {
+        bondSale,
+        payer
+      }
+    })
+  }
+
+  async setVelocityControllerTransaction(setVelocityController: SetVelocityController) {
+    const ix = await this.setVelocityControllerInstruction(setVelocityController)
+
+    return new Transaction().add(ix)
+  }
+
+  async setVelocityController(setVelocityController: SetVelocityController, signer?: Keypair) {
+    const tx = await this.setVelocityControllerTransaction(setVelocityController)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async changeCeilModeInstruction(changeCeilMode: ChangeCeilMode) {
+    const { bondSale, ceilMode } = changeCeilMode
+    const payer = changeCeilMode.payer ?? this.wallet.publicKey
+
+    return this.program.instruction.changeCeilMode(ceilMode, {
+      accounts: {
+        bondSale,
+        payer
+      }
+    })
+  }
+
+  async changeCeilModeTransaction(changeCeilMode: ChangeCeilMode) {
+    const ix = await this.changeCeilModeInstruction(changeCeilMode)
+
+    return new Transaction().add(ix)
+  }
+
+  async changeCeilMode(changeCeilMode: ChangeCeilMode, signer?: Keypair) {
+    const tx = await this.changeCeilModeTransaction(changeCeilMode)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async createPriceHistoryInstruction(createPriceHistory: CreatePriceHistory) {
+    const { bondSale } = createPriceHistory
+    const payer = createPriceHistory.payer ?? this.wallet.publicKey
+    const { priceHistoryAddress } = await this.getPriceHistoryAddress(bondSale)
+
+    return this.program.instruction.createPriceHistory({
+      accounts: {
+        bondSale,
+        priceHistory: priceHistoryAddress,
+        payer,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createPriceHistoryTransaction(createPriceHistory: CreatePriceHistory) {
+    const ix = await this.createPriceHistoryInstruction(createPriceHistory)
+
+    return new Transaction().add(ix)
+  }
+
+  async createPriceHistory(createPriceHistory: CreatePriceHistory, signer?: Keypair) {
+    const tx = await this.createPriceHistoryTransaction(createPriceHistory)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async pokePriceInstruction(pokePrice: PokePrice) {
+    const { bondSale } = pokePrice
+    const bondSaleStruct = await this.getBondSale(bondSale)
+    const remainingAccounts = await this.getPriceHistoryAccounts(
+      bondSale,
+      pokePrice.withPriceHistory
+    )
+
+    return this.program.instruction.pokePrice({
+      accounts: {
+        bondSale,
+        oracle: bondSaleStruct.oracle
+      },
+      remainingAccounts
+    })
+  }
+
+  async pokePriceTransaction(pokePrice: PokePrice) {
+    const ix = await this.pokePriceInstruction(pokePrice)
+
+    return new Transaction().add(ix)
+  }
+
+  async pokePrice(pokePrice: PokePrice, signer?: Keypair) {
+    const tx = await this.pokePriceTransaction(pokePrice)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async setVolumeTiersInstruction(setVolumeTiers: SetVolumeTiers) {
+    const { bondSale, volumeTiers } = setVolumeTiers
+    const payer = setVolumeTiers.payer ?? this.wallet.publicKey
+
+    return this.program.instruction.setVolumeTiers(volumeTiers, {
+      accounts: {
+        bondSale,
+        payer
+      }
+    })
+  }
+
+  async setVolumeTiersTransaction(setVolumeTiers: SetVolumeTiers) {
+    const ix = await this.setVolumeTiersInstruction(setVolumeTiers)
+
+    return new Transaction().add(ix)
+  }
+
+  async setVolumeTiers(setVolumeTiers: SetVolumeTiers, signer?: Keypair) {
+    const tx = await this.setVolumeTiersTransaction(setVolumeTiers)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async setVestingTermsInstruction(setVestingTerms: SetVestingTerms) {
+    const { bondSale, vestingTerms } = setVestingTerms
+    const payer = setVestingTerms.payer ?? this.wallet.publicKey
+
+    return this.program.instruction.setVestingTerms(vestingTerms, {
+      accounts: {
+        bondSale,
+        payer
+      }
+    })
+  }
+
+  async setVestingTermsTransaction(setVestingTerms: SetVestingTerms) {
+    const ix = await this.setVestingTermsInstruction(setVestingTerms)
+
+    return new Transaction().add(ix)
+  }
+
+  async setVestingTerms(setVestingTerms: SetVestingTerms, signer?: Keypair) {
+    const tx = await this.setVestingTermsTransaction(setVestingTerms)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+
+  async claimSaleQuoteInstruction(claimSaleQuote: ClaimSaleQuote) {
+    const { bondSale, tokenQuote } = claimSaleQuote
+    const payer = claimSaleQuote.payer ?? this.wallet.publicKey
+    const { stateAddress } = await this.getStateAddress()
+    const { programAuthority } = await this.getProgramAuthority()
+    const { saleQuoteAddress } = await this.getSaleQuoteAddress(bondSale, tokenQuote)
+    const saleQuote = await this.getSaleQuote(bondSale, tokenQuote)
+    const { proceedsSplits } = await this.getBondSale(bondSale)
+
+    return this.program.instruction.claimSaleQuote({
+      accounts: {
+        state: stateAddress,
+        bondSale,
+        saleQuote: saleQuoteAddress,
+        tokenQuoteAccount: saleQuote.tokenQuoteAccount,
+        proceedsDestination: saleQuote.proceedsDestination,
+        payer,
+        authority: programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      },
+      remainingAccounts: await this.getSaleQuoteSplitRecipients(proceedsSplits, tokenQuote)
+    })
+  }
+
+  async claimSaleQuoteTransaction(claimSaleQuote: ClaimSaleQuote) {
+    const ix = await this.claimSaleQuoteInstruction(claimSaleQuote)
+
+    return new Transaction().add(ix)
+  }
+
+  async claimSaleQuote(claimSaleQuote: ClaimSaleQuote, signer?: Keypair) {
+    const tx = await this.claimSaleQuoteTransaction(claimSaleQuote)
+
+    if (signer === undefined) {
+      await signAndSend(tx, this.connection, undefined, this.wallet)
+    } else {
+      await signAndSend(tx, this.connection, [signer])
+    }
+  }
+}
+
+export interface InitBondSale {
+  tokenBond: Token
+  tokenQuote: Token
+  payerBondAccount: PublicKey
+  proceedsDestination: PublicKey
+  payer?: PublicKey
+  floorPrice: BN
+  upBound: BN
+  velocity: BN
+  supply: BN
+  duration: BN
+  vestingTime: BN
+  softCap?: BN
+  hardCap?: BN
+  proceedsVestingTime?: BN
+  proceedsSplits?: ProceedsSplitParams[]
+  // payer has to be the mint authority of tokenBond
+  mintOnDemand?: boolean
+}
+
+export interface ProceedsSplitParams {
+  recipient: PublicKey
+  bps: number
 }
 
 export interface CreateBond {
   bondSale: PublicKey
-  ownerQuoteAccount: PublicKey
+  // defaults to the owner, which pays in lamports on native sales
+  ownerQuoteAccount?: PublicKey
   priceLimit: BN
   amount: BN
   owner?: PublicKey
+  recipient?: PublicKey
+  payer?: PublicKey
+  expiresAt?: BN
+  referrerQuoteAccount?: PublicKey
+  // index of one of the vesting terms of the sale, the sale vesting time when omitted
+  vestingTerm?: number
+}
+
+export interface CreateBondExactIn {
+  bondSale: PublicKey
+  // defaults to the owner, which pays in lamports on native sales
+  ownerQuoteAccount?: PublicKey
+  quoteAmount: BN
+  minBondOut: BN
+  owner?: PublicKey
+  recipient?: PublicKey
+  payer?: PublicKey
+  expiresAt?: BN
+  referrerQuoteAccount?: PublicKey
+  vestingTerm?: number
 }
 
 export interface ChangeVelocity {
@@ -602,22 +1702,51 @@ export interface ChangeUpBound {
 
 export interface ClaimQuote {
   bondSale: PublicKey
-  payerQuoteAccount: PublicKey
   payer?: PublicKey
 }
 
 export interface ClaimBond {
   bondSale: PublicKey
   ownerBondAccount: PublicKey
+  recipientBondAccount?: PublicKey
+  owner?: PublicKey
+  bondId: BN
+  amount?: BN
+}
+
+export interface RefundBond {
+  bondSale: PublicKey
+  bondId: BN
+  ownerQuoteAccount?: PublicKey
+  owner?: PublicKey
+}
+
+export interface CloseBond {
+  bondSale: PublicKey
+  bondId: BN
+  owner?: PublicKey
+}
+
+export interface SetBondDelegate {
+  bondSale: PublicKey
+  bondId: BN
+  delegate: PublicKey
+  tip?: BN
   owner?: PublicKey
+}
+
+export interface ClaimBondDelegated {
+  bondSale: PublicKey
   bondId: BN
+  // associated token account of the bond owner
+  ownerBondAccount: PublicKey
+  delegateBondAccount: PublicKey
+  delegate?: PublicKey
 }
 
 export interface EndBondSale {
   bondSale: PublicKey
-  payerQuoteAccount: PublicKey
   payerBondAccount: PublicKey
-  adminQuoteAccount: PublicKey
   payer?: PublicKey
 }
 
@@ -627,11 +1756,174 @@ export interface ChangeFee {
   newFee: BN
 }
 
-export interface WithdrawFee {
+export enum FeeMode {
+  Quote = 0,
+  Bond = 1,
+  Hybrid = 2
+}
+
+export interface ChangeFeeMode {
   bondSale: PublicKey
+  admin?: PublicKey
+  feeMode: FeeMode
+  bondFee: BN
+}
+
+export interface ChangeReferralFee {
+  bondSale: PublicKey
+  admin?: PublicKey
+  referralFee: BN
+}
+
+export interface CreateReferral {
+  bondSale: PublicKey
+  referrerQuoteAccount: PublicKey
+  payer?: PublicKey
+}
+
+export interface ClaimReferral {
+  bondSale: PublicKey
+  referrerQuoteAccount: PublicKey
+  referrer?: PublicKey
+}
+
+export interface CreateFeeVault {
+  tokenQuote: PublicKey
+  payer?: PublicKey
+}
+
+export interface WithdrawFee {
+  tokenQuote: PublicKey
   adminQuoteAccount: PublicKey
   admin?: PublicKey
 }
+
+export interface WithdrawBondFee {
+  bondSale: PublicKey
+  adminBondAccount: PublicKey
+  admin?: PublicKey
+}
+
+export interface AddSaleQuote {
+  bondSale: PublicKey
+  tokenQuote: PublicKey
+  // associated token account of the owner of the sale proceeds destination
+  proceedsDestination: PublicKey
+  // value of one tokenQuote in the main quote token
+  rate: BN
+  payer?: PublicKey
+}
+
+export interface CreateBondWithSaleQuote {
+  bondSale: PublicKey
+  tokenQuote: PublicKey
+  ownerQuoteAccount: PublicKey
+  priceLimit: BN
+  amount: BN
+  owner?: PublicKey
+  recipient?: PublicKey
+  payer?: PublicKey
+  expiresAt?: BN
+  vestingTerm?: number
+}
+
+export interface ClaimSaleQuote {
+  bondSale: PublicKey
+  tokenQuote: PublicKey
+  payer?: PublicKey
+}
+
+export enum PriceMode {
+  Fixed = 0,
+  Oracle = 1,
+  Debt = 2
+}
+
+export interface ChangePriceMode {
+  bondSale: PublicKey
+  priceMode: PriceMode
+  // price feed account, see PRICE_FEED_SIZE for its layout
+  oracle?: PublicKey
+  // floor is the feed price times (1 - discount)
+  discount?: BN
+  maxStaleness?: BN
+  // largest confidence interval as a fraction of the price
+  maxConfidence?: BN
+  // program that owns the price feed account
+  oracleProgram?: PublicKey
+  payer?: PublicKey
+}
+
+export interface SetDebtPricing {
+  bondSale: PublicKey
+  // price is controlVariable * debt / supply, never below the floor price
+  controlVariable: BN
+  // zero for no limit
+  maxPayout?: BN
+  maxDebt?: BN
+  // controlVariable moves by adjustmentRate towards adjustmentTarget every adjustmentBuffer seconds
+  adjustmentTarget?: BN
+  adjustmentRate?: BN
+  adjustmentBuffer?: BN
+  payer?: PublicKey
+}
+
+export interface SetVelocityController {
+  bondSale: PublicKey
+  // velocity moves by gain * (elapsed fraction - sold fraction) on every purchase
+  // zero gain turns the controller off
+  gain: BN
+  minVelocity: BN
+  maxVelocity: BN
+  payer?: PublicKey
+}
+
+// purchases that would push the price above the ceiling are capped or rejected
+export enum CeilMode {
+  Cap = 0,
+  Reject = 1
+}
+
+export interface ChangeCeilMode {
+  bondSale: PublicKey
+  ceilMode: CeilMode
+  payer?: PublicKey
+}
+
+export interface CreatePriceHistory {
+  bondSale: PublicKey
+  payer?: PublicKey
+}
+
+// withPriceHistory also records an observation in the price history of the sale
+export interface PokePrice {
+  bondSale: PublicKey
+  withPriceHistory?: boolean
+}
+
+// sorted by minAmount, purchases get the discount of the highest tier they reach
+export interface VolumeTierParams {
+  minAmount: BN
+  discount: BN
+}
+
+export interface SetVolumeTiers {
+  bondSale: PublicKey
+  volumeTiers: VolumeTierParams[]
+  payer?: PublicKey
+}
+
+// sorted by vestingTime, each term buys at discount off the curve price
+export interface VestingTermParams {
+  vestingTime: BN
+  discount: BN
+}
+
+export interface SetVestingTerms {
+  bondSale: PublicKey
+  vestingTerms: VestingTermParams[]
+  payer?: PublicKey
+}
 export interface BondStruct {
   bondSale: PublicKey
   tokenBond: PublicKey
@@ -641,6 +1933,43 @@ export interface BondStruct {
   vestingStart: BN
   vestingEnd: BN
   id: BN
+  claimedAmount: TokenAmount
+  delegate: PublicKey
+  delegateTip: Decimal
+  rentPayer: PublicKey
+  quoteAmount: TokenAmount
+  feeAmount: TokenAmount
+  referral: PublicKey
+  referralAmount: TokenAmount
+  bondFee: Decimal
+  bondFeeAmount: TokenAmount
+  saleQuote: PublicKey
+  vestingTime: BN
+  vestingDiscount: Decimal
+}
+
+export interface StateStruct {
+  admin: PublicKey
+  authority: PublicKey
+  nextBondSale: BN
+  nonce: number
+  bump: number
+  totalFee: TokenAmount
+  totalWithdrawn: TokenAmount
+}
+
+export interface FeeVaultStruct {
+  tokenQuote: PublicKey
+  tokenAccount: PublicKey
+  bump: number
+}
+
+export interface ReferralStruct {
+  bondSale: PublicKey
+  referrerQuoteAccount: PublicKey
+  amount: TokenAmount
+  bump: number
+  referrer: PublicKey
 }
 
 export interface BondSaleStruct {
@@ -664,6 +1993,88 @@ export interface BondSaleStruct {
   vestingTime: BN
   nextBond: BN
   id: BN
+  softCap: TokenAmount
+  quoteRaised: TokenAmount
+  hardCap: TokenAmount
+  status: number
+  proceedsVestingTime: BN
+  proceedsDestination: PublicKey
+  proceedsSplits: ProceedsSplit[]
+  referralFee: Decimal
+  referralAmount: TokenAmount
+  feeMode: number
+  bondFee: Decimal
+  bondFeeAmount: TokenAmount
+  supplyMode: number
+  mintedAmount: TokenAmount
+  saleQuoteRaised: TokenAmount
+  saleQuoteCount: number
+  priceMode: number
+  oracle: PublicKey
+  oracleDiscount: Decimal
+  oracleMaxStaleness: BN
+  oracleMaxConfidence: Decimal
+  controlVariable: Decimal
+  totalDebt: TokenAmount
+  lastDecay: BN
+  maxPayout: TokenAmount
+  maxDebt: TokenAmount
+  adjustmentTarget: Decimal
+  adjustmentRate: Decimal
+  adjustmentBuffer: BN
+  lastAdjustment: BN
+  velocityGain: Decimal
+  minVelocity: Decimal
+  maxVelocity: Decimal
+  ceilMode: number
+  priceCumulative: BN
+  lastObservation: BN
+  lastObservedPrice: Decimal
+  volumeTiers: VolumeTier[]
+  vestingTerms: VestingTerm[]
+  oracleProgram: PublicKey
+}
+
+export interface SaleQuoteStruct {
+  bondSale: PublicKey
+  tokenQuote: PublicKey
+  tokenQuoteAccount: PublicKey
+  proceedsDestination: PublicKey
+  rate: Decimal
+  quoteAmount: TokenAmount
+  quoteRaised: TokenAmount
+  bump: number
+  splitsDistributed: TokenAmount[]
+}
+
+export interface Observation {
+  timestamp: BN
+  priceCumulative: BN
+}
+
+// head is the index of the latest of count observations
+export interface PriceHistoryStruct {
+  bondSale: PublicKey
+  head: number
+  count: number
+  observations: Observation[]
+  bump: number
+}
+
+export interface VolumeTier {
+  minAmount: TokenAmount
+  discount: Decimal
+}
+
+export interface VestingTerm {
+  vestingTime: BN
+  discount: Decimal
+}
+
+export interface ProceedsSplit {
+  recipient: PublicKey
+  bps: number
+  distributed: TokenAmount
 }
 
 export interface Decimal {
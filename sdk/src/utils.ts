@@ -12,7 +12,44 @@ import { Decimal } from './sale'
 export enum ERROR {
   ACCOUNT_NOT_EXISTS = 'Error: Account does not exist',
   CONSTRAINT_RAW = '0x7d3',
-  PRICE_LIMIT_EXCEEDED = '0x1773'
+  PRICE_LIMIT_EXCEEDED = '0x1773',
+  CLAIM_AMOUNT_EXCEEDED = '0x1774',
+  DELEGATE_TIP_EXCEEDED = '0x1775',
+  BOND_NOT_FULLY_CLAIMED = '0x1776',
+  TRANSACTION_EXPIRED = '0x1777',
+  BOND_OUT_BELOW_MINIMUM = '0x1778',
+  SOFT_CAP_NOT_REACHED = '0x1779',
+  REFUND_NOT_AVAILABLE = '0x177a',
+  SALE_SOLD_OUT = '0x177b',
+  INVALID_HARD_CAP = '0x177c',
+  INVALID_PROCEEDS_SPLITS = '0x177d',
+  INVALID_SPLIT_RECIPIENT = '0x177e',
+  INVALID_REFERRAL_FEE = '0x177f',
+  INVALID_REFERRAL = '0x1780',
+  INVALID_FEE_MODE = '0x1781',
+  INVALID_QUOTE_ACCOUNT = '0x1782',
+  INVALID_MINT_AUTHORITY = '0x1783',
+  INVALID_RATE = '0x1784',
+  INVALID_PRICE_MODE = '0x1785',
+  INVALID_ORACLE = '0x1786',
+  STALE_ORACLE = '0x1787',
+  ORACLE_CONFIDENCE_EXCEEDED = '0x1788',
+  MAX_PAYOUT_EXCEEDED = '0x1789',
+  MAX_DEBT_EXCEEDED = '0x178a',
+  INVALID_VELOCITY_BOUNDS = '0x178b',
+  CEIL_PRICE_EXCEEDED = '0x178c',
+  INVALID_CEIL_MODE = '0x178d',
+  INVALID_PRICE_HISTORY = '0x178e',
+  INVALID_VOLUME_TIERS = '0x178f',
+  INVALID_VESTING_TERMS = '0x1790',
+  INVALID_VESTING_TERM = '0x1791',
+  SELF_REFERRAL = '0x1792',
+  REFERRAL_CLAIM_EXPIRED = '0x1793'
+}
+
+export enum BondSaleStatus {
+  ACTIVE = 0,
+  SOLD_OUT = 1
 }
 
 export const DECIMAL = 12
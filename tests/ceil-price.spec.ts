@@ -0,0 +1,140 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CeilMode, CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('ceil-price', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  const createBondVars = (amount: number): CreateBond => ({
+    amount: new BN(amount),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR,
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+  })
+
+  it('#changeCeilMode() with an unknown mode', async () => {
+    await assertThrowsAsync(
+      bonds.changeCeilMode(
+        { bondSale: bondSalePubkey, ceilMode: 2, payer: bondInitPayer.publicKey },
+        bondInitPayer
+      ),
+      ERROR.INVALID_CEIL_MODE
+    )
+  })
+
+  it('#changeCeilMode() by other than payer', async () => {
+    await assertThrowsAsync(
+      bonds.changeCeilMode(
+        { bondSale: bondSalePubkey, ceilMode: CeilMode.Reject, payer: bondOwner.publicKey },
+        bondOwner
+      ),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#createBond() rejected above the ceiling', async () => {
+    await bonds.createBond(createBondVars(800), bondOwner)
+    // ceiling drops to 1.2 under the current price of about 1.4
+    await bonds.changeUpBound(
+      { bondSale: bondSalePubkey, upBound: DENOMINATOR.divn(5), payer: bondInitPayer.publicKey },
+      bondInitPayer
+    )
+    await bonds.changeCeilMode(
+      { bondSale: bondSalePubkey, ceilMode: CeilMode.Reject, payer: bondInitPayer.publicKey },
+      bondInitPayer
+    )
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.equal(bondSale.ceilMode, CeilMode.Reject)
+    await assertThrowsAsync(
+      bonds.createBond(createBondVars(10), bondOwner),
+      ERROR.CEIL_PRICE_EXCEEDED
+    )
+  })
+
+  it('#createBond() capped at the ceiling', async () => {
+    await bonds.changeCeilMode(
+      { bondSale: bondSalePubkey, ceilMode: CeilMode.Cap, payer: bondInitPayer.publicKey },
+      bondInitPayer
+    )
+    const balanceBefore = (await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount
+
+    await bonds.createBond(createBondVars(10), bondOwner)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const balanceAfter = (await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount
+    assert.ok(bondSale.previousPrice.v.eq(DENOMINATOR.muln(12).divn(10)))
+    assert.ok(balanceBefore.sub(balanceAfter).eqn(12))
+  })
+})
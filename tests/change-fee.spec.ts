@@ -50,6 +50,7 @@ describe('claim-quote', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
 
   describe('changeFee', () => {
@@ -57,11 +58,13 @@ describe('claim-quote', () => {
       const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
       await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+      const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
       const initBondSaleVars: InitBondSale = {
         supply: new BN(1000),
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -0,0 +1,200 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  ClaimBondDelegated,
+  CreateBond,
+  InitBondSale,
+  SetBondDelegate
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { almostEqual, assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('claim-bond-delegated', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+  const keeper = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerBondAccount: PublicKey
+  let keeperBondAccount: PublicKey
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12),
+      connection.requestAirdrop(keeper.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    ownerBondAccount = await tokenBond.createAssociatedTokenAccount(bondOwner.publicKey)
+    keeperBondAccount = await tokenBond.createAccount(keeper.publicKey)
+  })
+
+  it('#initBondSale()', async () => {
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 10000)
+
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(10000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(10)
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#createBond()', async () => {
+    const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(1000),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.createBond(createBondVars, bondOwner)
+  })
+
+  it('#claimBondDelegated() without approval', async () => {
+    const claimBondDelegatedVars: ClaimBondDelegated = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      ownerBondAccount,
+      delegateBondAccount: keeperBondAccount,
+      delegate: keeper.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.claimBondDelegated(claimBondDelegatedVars, keeper),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#setBondDelegate() with too high tip', async () => {
+    const setBondDelegateVars: SetBondDelegate = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      delegate: keeper.publicKey,
+      tip: toDecimal(new BN(2), 2).v,
+      owner: bondOwner.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.setBondDelegate(setBondDelegateVars, bondOwner),
+      ERROR.DELEGATE_TIP_EXCEEDED
+    )
+  })
+
+  it('#setBondDelegate()', async () => {
+    const setBondDelegateVars: SetBondDelegate = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      delegate: keeper.publicKey,
+      tip: toDecimal(new BN(1), 2).v,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.setBondDelegate(setBondDelegateVars, bondOwner)
+
+    const bond = await bonds.getBondById(bondSalePubkey, new BN(0))
+    assert.ok(bond.account.delegate.equals(keeper.publicKey))
+  })
+
+  it('#claimBondDelegated() to foreign account', async () => {
+    const claimBondDelegatedVars: ClaimBondDelegated = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      ownerBondAccount: keeperBondAccount,
+      delegateBondAccount: keeperBondAccount,
+      delegate: keeper.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.claimBondDelegated(claimBondDelegatedVars, keeper),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#claimBondDelegated() to non-associated account of the owner', async () => {
+    const claimBondDelegatedVars: ClaimBondDelegated = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      ownerBondAccount: await tokenBond.createAccount(bondOwner.publicKey),
+      delegateBondAccount: keeperBondAccount,
+      delegate: keeper.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.claimBondDelegated(claimBondDelegatedVars, keeper),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#claimBondDelegated()', async () => {
+    await sleep(11000)
+
+    const claimBondDelegatedVars: ClaimBondDelegated = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      ownerBondAccount,
+      delegateBondAccount: keeperBondAccount,
+      delegate: keeper.publicKey
+    }
+    await bonds.claimBondDelegated(claimBondDelegatedVars, keeper)
+
+    const ownerAmount = (await tokenBond.getAccountInfo(ownerBondAccount)).amount
+    const keeperAmount = (await tokenBond.getAccountInfo(keeperBondAccount)).amount
+    assert.ok(almostEqual(ownerAmount, new BN(990)))
+    assert.ok(almostEqual(keeperAmount, new BN(10)))
+    assert.ok(ownerAmount.add(keeperAmount).eqn(1000))
+  })
+})
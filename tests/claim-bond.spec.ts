@@ -3,10 +3,10 @@ import { Provider, BN } from '@project-serum/anchor'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { Keypair, PublicKey } from '@solana/web3.js'
 import { Network } from '@invariant-labs/bonds-sdk'
-import { ClaimBond, CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
-import { DENOMINATOR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { ClaimBond, CloseBond, CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
 import { assert } from 'chai'
-import { almostEqual, createToken } from './testUtils'
+import { almostEqual, assertThrowsAsync, createToken } from './testUtils'
 import { Bonds } from '@invariant-labs/bonds-sdk/src'
 import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
 
@@ -20,6 +20,7 @@ describe('claim-bond', () => {
   const admin = Keypair.generate()
   const bondInitPayer = Keypair.generate()
   const bondOwner = Keypair.generate()
+  const recipient = Keypair.generate()
 
   let bonds: Bonds
   let tokenBond: Token
@@ -51,6 +52,7 @@ describe('claim-bond', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
 
   describe('user', () => {
@@ -58,11 +60,13 @@ describe('claim-bond', () => {
       const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
       await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+      const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
       const initBondSaleVars: InitBondSale = {
         supply: new BN(1000),
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -142,11 +146,13 @@ describe('claim-bond', () => {
       const payerBondAccount = await tokenBond.createAccount(wallet.publicKey)
       await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+      const proceedsDestination = await tokenQuote.createAccount(wallet.publicKey)
       const initBondSaleVars: InitBondSale = {
         supply: new BN(1000),
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -215,4 +221,137 @@ describe('claim-bond', () => {
       assert.isUndefined((await bonds.getAllOwnerBonds(wallet.publicKey))[0].account)
     })
   })
+
+  describe('partial claim', () => {
+    it('#initBondSale()', async () => {
+      const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+      await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+      const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+      const initBondSaleVars: InitBondSale = {
+        supply: new BN(1000),
+        duration: new BN(100),
+        floorPrice: DENOMINATOR,
+        payerBondAccount,
+        proceedsDestination,
+        tokenBond,
+        tokenQuote,
+        upBound: DENOMINATOR.divn(2),
+        velocity: DENOMINATOR.divn(2),
+        payer: bondInitPayer.publicKey,
+        vestingTime: new BN(10)
+      }
+
+      bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+    })
+
+    it('#createBond()', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+      const createBondVars: CreateBond = {
+        amount: new BN(100),
+        priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey
+      }
+
+      await bonds.createBond(createBondVars, bondOwner)
+    })
+
+    it('#claimBond() to recipient', async () => {
+      await sleep(5000)
+
+      const ownerBondAccount = await tokenBond.createAccount(bondOwner.publicKey)
+      const recipientBondAccount = await tokenBond.createAccount(recipient.publicKey)
+      const claimBondVars: ClaimBond = {
+        bondSale: bondSalePubkey,
+        ownerBondAccount,
+        recipientBondAccount,
+        bondId: new BN(0),
+        owner: bondOwner.publicKey,
+        amount: new BN(20)
+      }
+      await bonds.claimBond(claimBondVars, bondOwner)
+
+      assert.ok((await tokenBond.getAccountInfo(recipientBondAccount)).amount.eqn(20))
+      assert.ok((await tokenBond.getAccountInfo(ownerBondAccount)).amount.eqn(0))
+
+      const bond = await bonds.getBondById(bondSalePubkey, new BN(0))
+      assert.ok(bond.account.claimedAmount.v.eqn(20))
+    })
+
+    it('#claimBond() above vested amount', async () => {
+      const ownerBondAccount = await tokenBond.createAccount(bondOwner.publicKey)
+      const claimBondVars: ClaimBond = {
+        bondSale: bondSalePubkey,
+        ownerBondAccount,
+        bondId: new BN(0),
+        owner: bondOwner.publicKey,
+        amount: new BN(100)
+      }
+
+      await assertThrowsAsync(
+        bonds.claimBond(claimBondVars, bondOwner),
+        ERROR.CLAIM_AMOUNT_EXCEEDED
+      )
+    })
+
+    it('#closeBond() not fully claimed', async () => {
+      const closeBondVars: CloseBond = {
+        bondSale: bondSalePubkey,
+        bondId: new BN(0),
+        owner: bondOwner.publicKey
+      }
+
+      await assertThrowsAsync(
+        bonds.closeBond(closeBondVars, bondOwner),
+        ERROR.BOND_NOT_FULLY_CLAIMED
+      )
+    })
+
+    it('#claimBond() rest after vesting end', async () => {
+      await sleep(6000)
+
+      const ownerBalance = await connection.getBalance(bondOwner.publicKey)
+      const ownerBondAccount = await tokenBond.createAccount(bondOwner.publicKey)
+      const claimBondVars: ClaimBond = {
+        bondSale: bondSalePubkey,
+        ownerBondAccount,
+        bondId: new BN(0),
+        owner: bondOwner.publicKey,
+        amount: new BN(80)
+      }
+      await bonds.claimBond(claimBondVars, bondOwner)
+
+      assert.ok((await tokenBond.getAccountInfo(ownerBondAccount)).amount.eqn(80))
+      assert.isUndefined(await bonds.getBondById(bondSalePubkey, new BN(0)))
+      assert.ok((await connection.getBalance(bondOwner.publicKey)) > ownerBalance)
+    })
+
+    it('#closeBond() empty bond', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+      const createBondVars: CreateBond = {
+        amount: new BN(0),
+        priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey
+      }
+      await bonds.createBond(createBondVars, bondOwner)
+
+      const closeBondVars: CloseBond = {
+        bondSale: bondSalePubkey,
+        bondId: new BN(1),
+        owner: bondOwner.publicKey
+      }
+      await bonds.closeBond(closeBondVars, bondOwner)
+
+      assert.isUndefined(await bonds.getBondById(bondSalePubkey, new BN(1)))
+    })
+  })
 })
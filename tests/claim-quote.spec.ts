@@ -1,12 +1,12 @@
 import * as anchor from '@project-serum/anchor'
 import { Provider, BN } from '@project-serum/anchor'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
-import { Keypair, PublicKey } from '@solana/web3.js'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
 import { Network } from '@invariant-labs/bonds-sdk'
 import { ClaimQuote, CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
-import { DENOMINATOR, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { DENOMINATOR, ERROR, signAndSend, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
 import { assert } from 'chai'
-import { createToken } from './testUtils'
+import { assertThrowsAsync, createToken } from './testUtils'
 import { Bonds } from '@invariant-labs/bonds-sdk/src'
 import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
 
@@ -52,6 +52,7 @@ describe('claim-quote', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
   describe('user', () => {
     it('#initBondSale()', async () => {
@@ -64,6 +65,7 @@ describe('claim-quote', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -94,7 +96,6 @@ describe('claim-quote', () => {
     it('#claimQuote()', async () => {
       const claimQuoteVars: ClaimQuote = {
         bondSale: bondSalePubkey,
-        payerQuoteAccount,
         payer: bondInitPayer.publicKey
       }
       await bonds.claimQuote(claimQuoteVars, bondInitPayer)
@@ -116,6 +117,7 @@ describe('claim-quote', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -143,8 +145,7 @@ describe('claim-quote', () => {
 
     it('#claimQuote()', async () => {
       const claimQuoteVars: ClaimQuote = {
-        bondSale: bondSalePubkey,
-        payerQuoteAccount
+        bondSale: bondSalePubkey
       }
       await bonds.claimQuote(claimQuoteVars)
 
@@ -153,4 +154,107 @@ describe('claim-quote', () => {
       assert.ok((await tokenQuote.getAccountInfo(payerQuoteAccount)).amount.eqn(101))
     })
   })
+
+  describe('treasury', () => {
+    const treasury = Keypair.generate()
+    let treasuryQuoteAccount: PublicKey
+    let otherSaleQuoteAccount: PublicKey
+
+    it('#initBondSale() with wrong mint', async () => {
+      const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+      const treasuryBondAccount = await tokenBond.createAccount(treasury.publicKey)
+      await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+      const initBondSaleVars: InitBondSale = {
+        supply: new BN(1000),
+        duration: new BN(100),
+        floorPrice: DENOMINATOR,
+        payerBondAccount,
+        proceedsDestination: treasuryBondAccount,
+        tokenBond,
+        tokenQuote,
+        upBound: DENOMINATOR.divn(2),
+        velocity: DENOMINATOR.divn(2),
+        payer: bondInitPayer.publicKey,
+        vestingTime: new BN(20)
+      }
+
+      await assertThrowsAsync(
+        bonds.initBondSale(initBondSaleVars, bondInitPayer),
+        ERROR.CONSTRAINT_RAW
+      )
+    })
+
+    it('#initBondSale()', async () => {
+      otherSaleQuoteAccount = (await bonds.getBondSale(bondSalePubkey)).tokenQuoteAccount
+      const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+      payerQuoteAccount = await tokenQuote.createAccount(bondInitPayer.publicKey)
+      treasuryQuoteAccount = await tokenQuote.createAccount(treasury.publicKey)
+      await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+      const initBondSaleVars: InitBondSale = {
+        supply: new BN(1000),
+        duration: new BN(100),
+        floorPrice: DENOMINATOR,
+        payerBondAccount,
+        proceedsDestination: treasuryQuoteAccount,
+        tokenBond,
+        tokenQuote,
+        upBound: DENOMINATOR.divn(2),
+        velocity: DENOMINATOR.divn(2),
+        payer: bondInitPayer.publicKey,
+        vestingTime: new BN(20)
+      }
+
+      bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+      assert.ok(bondSale.proceedsDestination.equals(treasuryQuoteAccount))
+    })
+
+    it('#createBond()', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+      const createBondVars: CreateBond = {
+        amount: new BN(100),
+        priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey
+      }
+
+      await bonds.createBond(createBondVars, bondOwner)
+    })
+
+    it('#claimQuote() from a vault of another sale', async () => {
+      const { tokenQuoteAccount } = await bonds.getBondSale(bondSalePubkey)
+      const ix = await bonds.claimQuoteInstruction({
+        bondSale: bondSalePubkey,
+        payer: bondInitPayer.publicKey
+      })
+      ix.keys
+        .filter(key => key.pubkey.equals(tokenQuoteAccount))
+        .forEach(key => (key.pubkey = otherSaleQuoteAccount))
+
+      await assertThrowsAsync(
+        signAndSend(new Transaction().add(ix), connection, [bondInitPayer]),
+        ERROR.CONSTRAINT_RAW
+      )
+    })
+
+    it('#claimQuote()', async () => {
+      const claimQuoteVars: ClaimQuote = {
+        bondSale: bondSalePubkey,
+        payer: bondInitPayer.publicKey
+      }
+      await bonds.claimQuote(claimQuoteVars, bondInitPayer)
+
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+      assert.ok(bondSale.quoteAmount.v.eqn(0))
+      assert.ok((await tokenQuote.getAccountInfo(treasuryQuoteAccount)).amount.eqn(101))
+      assert.ok((await tokenQuote.getAccountInfo(payerQuoteAccount)).amount.eqn(0))
+    })
+  })
 })
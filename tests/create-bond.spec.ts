@@ -20,6 +20,8 @@ describe('create-bond', () => {
   const admin = Keypair.generate()
   const bondInitPayer = Keypair.generate()
   const bondOwner = Keypair.generate()
+  const rentPayer = Keypair.generate()
+  const recipient = Keypair.generate()
 
   let bonds: Bonds
   let tokenBond: Token
@@ -39,7 +41,8 @@ describe('create-bond', () => {
       connection.requestAirdrop(admin.publicKey, 1e12),
       connection.requestAirdrop(wallet.publicKey, 1e12),
       connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
-      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+      connection.requestAirdrop(bondOwner.publicKey, 1e12),
+      connection.requestAirdrop(rentPayer.publicKey, 1e12)
     ])
 
     const tokens = await Promise.all([
@@ -51,17 +54,20 @@ describe('create-bond', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
   describe('user', () => {
     it('#initBondSale()', async () => {
       const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
       await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+      const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
       const initBondSaleVars: InitBondSale = {
         supply: new BN(1000),
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -92,6 +98,31 @@ describe('create-bond', () => {
       assert.ok(bond.bondAmount.v.eqn(100))
       assert.ok(bond.owner.toString() === bondOwner.publicKey.toString())
     })
+
+    it('#createBond() for recipient', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+      const ownerBalance = await connection.getBalance(bondOwner.publicKey)
+
+      const createBondVars: CreateBond = {
+        amount: new BN(100),
+        priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey,
+        recipient: recipient.publicKey,
+        payer: rentPayer.publicKey
+      }
+
+      const bondPub = await bonds.createBond(createBondVars, bondOwner, rentPayer)
+      const bond = await bonds.getBondByAddress(bondPub)
+
+      assert.ok(bond.bondAmount.v.eqn(100))
+      assert.ok(bond.owner.equals(recipient.publicKey))
+      assert.ok(bond.rentPayer.equals(rentPayer.publicKey))
+      assert.equal(await connection.getBalance(bondOwner.publicKey), ownerBalance)
+    })
   })
 
   describe('wallet', () => {
@@ -99,11 +130,13 @@ describe('create-bond', () => {
       const payerBondAccount = await tokenBond.createAccount(wallet.publicKey)
       await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+      const proceedsDestination = await tokenQuote.createAccount(wallet.publicKey)
       const initBondSaleVars: InitBondSale = {
         supply: new BN(1000),
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -0,0 +1,147 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  CreateBond,
+  InitBondSale,
+  PriceMode,
+  SetDebtPricing
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('debt-pricing', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+  let setDebtPricingVars: SetDebtPricing
+
+  const createBondVars = (amount: number): CreateBond => ({
+    amount: new BN(amount),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR.divn(2),
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    setDebtPricingVars = {
+      bondSale: bondSalePubkey,
+      controlVariable: DENOMINATOR.muln(4),
+      maxPayout: new BN(300),
+      maxDebt: new BN(560),
+      payer: bondInitPayer.publicKey
+    }
+  })
+
+  it('#setDebtPricing() by other than payer', async () => {
+    await assertThrowsAsync(
+      bonds.setDebtPricing({ ...setDebtPricingVars, payer: bondOwner.publicKey }, bondOwner),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#setDebtPricing()', async () => {
+    await bonds.setDebtPricing(setDebtPricingVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.equal(bondSale.priceMode, PriceMode.Debt)
+    assert.ok(bondSale.controlVariable.v.eq(DENOMINATOR.muln(4)))
+    assert.ok(bondSale.maxPayout.v.eqn(300))
+    assert.ok(bondSale.maxDebt.v.eqn(560))
+  })
+
+  it('#createBond() without debt pays the floor', async () => {
+    await bonds.createBond(createBondVars(250), bondOwner)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.previousPrice.v.eq(DENOMINATOR.divn(2)))
+    assert.ok(bondSale.totalDebt.v.eqn(250))
+  })
+
+  it('#createBond() above max payout', async () => {
+    await assertThrowsAsync(
+      bonds.createBond(createBondVars(301), bondOwner),
+      ERROR.MAX_PAYOUT_EXCEEDED
+    )
+  })
+
+  it('#createBond() prices the debt', async () => {
+    await bonds.createBond(createBondVars(300), bondOwner)
+
+    // control variable 4 times a debt ratio just below 0.25
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.previousPrice.v.lte(DENOMINATOR))
+    assert.ok(bondSale.previousPrice.v.gt(DENOMINATOR.muln(9).divn(10)))
+    assert.ok(bondSale.totalDebt.v.gtn(500))
+  })
+
+  it('#createBond() above max debt', async () => {
+    await assertThrowsAsync(
+      bonds.createBond(createBondVars(100), bondOwner),
+      ERROR.MAX_DEBT_EXCEEDED
+    )
+  })
+})
@@ -53,6 +53,7 @@ describe('end-bond-sale', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
   describe('bondInitPayer', () => {
     it('#initBondSale()', async () => {
@@ -65,6 +66,7 @@ describe('end-bond-sale', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -93,13 +95,9 @@ describe('end-bond-sale', () => {
     })
 
     it('#endBondSale()', async () => {
-      const adminQuoteAccount = await tokenQuote.createAccount(admin.publicKey)
-
       const endBondSaleVars: EndBondSale = {
         bondSale: bondSalePubkey,
-        payerQuoteAccount,
         payerBondAccount,
-        adminQuoteAccount,
         payer: bondInitPayer.publicKey
       }
 
@@ -121,6 +119,7 @@ describe('end-bond-sale', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -147,12 +146,8 @@ describe('end-bond-sale', () => {
     })
 
     it('#endBondSale()', async () => {
-      const adminQuoteAccount = await tokenQuote.createAccount(admin.publicKey)
-
       const endBondSaleVars: EndBondSale = {
         bondSale: bondSalePubkey,
-        adminQuoteAccount,
-        payerQuoteAccount,
         payerBondAccount
       }
 
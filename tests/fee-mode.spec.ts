@@ -0,0 +1,172 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  ChangeFeeMode,
+  ClaimBond,
+  CreateBond,
+  FeeMode,
+  InitBondSale
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('fee-mode', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+  })
+
+  const initBondSale = async () => {
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1)
+    }
+
+    return await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  }
+
+  const buyAndClaim = async (bondSalePubkey: PublicKey, bondId: BN) => {
+    const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+    await bonds.createBond(createBondVars, bondOwner)
+    await sleep(2000)
+
+    const ownerBondAccount = await tokenBond.createAccount(bondOwner.publicKey)
+    const claimBondVars: ClaimBond = {
+      bondSale: bondSalePubkey,
+      ownerBondAccount,
+      bondId,
+      owner: bondOwner.publicKey
+    }
+    await bonds.claimBond(claimBondVars, bondOwner)
+
+    return (await tokenBond.getAccountInfo(ownerBondAccount)).amount
+  }
+
+  it('rejects invalid fee mode', async () => {
+    const bondSalePubkey = await initBondSale()
+
+    const changeFeeModeVars: ChangeFeeMode = {
+      bondSale: bondSalePubkey,
+      admin: admin.publicKey,
+      feeMode: 3,
+      bondFee: toDecimal(new BN(1), 1).v
+    }
+    await assertThrowsAsync(bonds.changeFeeMode(changeFeeModeVars, admin), ERROR.INVALID_FEE_MODE)
+  })
+
+  it('bond side', async () => {
+    const bondSalePubkey = await initBondSale()
+
+    const changeFeeModeVars: ChangeFeeMode = {
+      bondSale: bondSalePubkey,
+      admin: admin.publicKey,
+      feeMode: FeeMode.Bond,
+      bondFee: toDecimal(new BN(1), 1).v
+    }
+    await bonds.changeFeeMode(changeFeeModeVars, admin)
+
+    const feeBefore = (await bonds.getState()).totalFee.v
+    const amount = await buyAndClaim(bondSalePubkey, new BN(0))
+    assert.ok(amount.eqn(90))
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.equal(bondSale.feeMode, FeeMode.Bond)
+    assert.ok(bondSale.bondFeeAmount.v.eqn(10))
+    assert.ok((await bonds.getState()).totalFee.v.eq(feeBefore))
+
+    const adminBondAccount = await tokenBond.createAccount(admin.publicKey)
+    await bonds.withdrawBondFee(
+      { bondSale: bondSalePubkey, adminBondAccount, admin: admin.publicKey },
+      admin
+    )
+
+    assert.ok((await tokenBond.getAccountInfo(adminBondAccount)).amount.eqn(10))
+    assert.ok((await bonds.getBondSale(bondSalePubkey)).bondFeeAmount.v.eqn(0))
+  })
+
+  it('hybrid', async () => {
+    const bondSalePubkey = await initBondSale()
+
+    const changeFeeModeVars: ChangeFeeMode = {
+      bondSale: bondSalePubkey,
+      admin: admin.publicKey,
+      feeMode: FeeMode.Hybrid,
+      bondFee: toDecimal(new BN(5), 2).v
+    }
+    await bonds.changeFeeMode(changeFeeModeVars, admin)
+
+    const feeBefore = (await bonds.getState()).totalFee.v
+    const amount = await buyAndClaim(bondSalePubkey, new BN(0))
+    assert.ok(amount.eqn(95))
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.bondFeeAmount.v.eqn(5))
+    assert.ok((await bonds.getState()).totalFee.v.gt(feeBefore))
+  })
+})
@@ -0,0 +1,191 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, CreateBondExactIn, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import {
+  BondSaleStatus,
+  DENOMINATOR,
+  ERROR,
+  toDecimal
+} from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('hard-cap', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let payerBondAccount: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+  })
+
+  it('#initBondSale() with hard cap below soft cap', async () => {
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(10),
+      softCap: new BN(200),
+      hardCap: new BN(100)
+    }
+
+    await assertThrowsAsync(
+      bonds.initBondSale(initBondSaleVars, bondInitPayer),
+      ERROR.INVALID_HARD_CAP
+    )
+  })
+
+  it('#initBondSale()', async () => {
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(10),
+      hardCap: new BN(100)
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#createBond() partially filled', async () => {
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(200),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(2), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    const bondPub = await bonds.createBond(createBondVars, bondOwner)
+    const bond = await bonds.getBondByAddress(bondPub)
+    const bondSaleAfter = await bonds.getBondSale(bondSalePubkey)
+
+    assert.ok(bond.bondAmount.v.gtn(0))
+    assert.ok(bond.bondAmount.v.ltn(200))
+    assert.ok(bondSaleAfter.quoteRaised.v.lten(100))
+    assert.equal(bondSaleAfter.status, BondSaleStatus.SOLD_OUT)
+  })
+
+  it('#createBond() after sold out', async () => {
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(10),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(2), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await assertThrowsAsync(bonds.createBond(createBondVars, bondOwner), ERROR.SALE_SOLD_OUT)
+  })
+
+  it('#createBondExactIn() partially filled', async () => {
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 500)
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(500),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(10),
+      hardCap: new BN(100)
+    }
+    const exactInSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    const createBondExactInVars: CreateBondExactIn = {
+      bondSale: exactInSalePubkey,
+      ownerQuoteAccount,
+      quoteAmount: new BN(200),
+      minBondOut: new BN(1),
+      owner: bondOwner.publicKey
+    }
+    const bondPub = await bonds.createBondExactIn(createBondExactInVars, bondOwner)
+
+    // rounding leaves the raised amount just under the cap, the sale still ends
+    const bond = await bonds.getBondByAddress(bondPub)
+    const bondSale = await bonds.getBondSale(exactInSalePubkey)
+    assert.ok(bond.bondAmount.v.gtn(0))
+    assert.ok(bondSale.quoteRaised.v.lten(100))
+    assert.equal(bondSale.status, BondSaleStatus.SOLD_OUT)
+
+    await assertThrowsAsync(
+      bonds.createBondExactIn(
+        { ...createBondExactInVars, quoteAmount: new BN(10), minBondOut: new BN(0) },
+        bondOwner
+      ),
+      ERROR.SALE_SOLD_OUT
+    )
+  })
+})
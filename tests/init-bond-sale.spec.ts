@@ -53,11 +53,13 @@ describe('init-bond-sale', () => {
     const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
     await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
     const initBondSaleVars: InitBondSale = {
       supply: new BN(1000),
       duration: new BN(100),
       floorPrice: DENOMINATOR,
       payerBondAccount,
+      proceedsDestination,
       tokenBond,
       tokenQuote,
       upBound: DENOMINATOR.divn(2),
@@ -84,11 +86,13 @@ describe('init-bond-sale', () => {
     const payerBondAccount = await tokenBond.createAccount(wallet.publicKey)
     await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
 
+    const proceedsDestination = await tokenQuote.createAccount(wallet.publicKey)
     const initBondSaleVars: InitBondSale = {
       supply: new BN(1000),
       duration: new BN(100),
       floorPrice: DENOMINATOR,
       payerBondAccount,
+      proceedsDestination,
       tokenBond,
       tokenQuote,
       upBound: DENOMINATOR.divn(2),
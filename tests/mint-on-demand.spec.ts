@@ -0,0 +1,143 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { ClaimBond, CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('mint-on-demand', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let payerBondAccount: PublicKey
+  let initBondSaleVars: InitBondSale
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, bondInitPayer),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    initBondSaleVars = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1),
+      mintOnDemand: true
+    }
+  })
+
+  it('#initBondSale() by other than mint authority', async () => {
+    const otherBond = await createToken(connection, wallet, mintAuthority)
+    const otherBondAccount = await otherBond.createAccount(bondInitPayer.publicKey)
+
+    await assertThrowsAsync(
+      bonds.initBondSale(
+        { ...initBondSaleVars, tokenBond: otherBond, payerBondAccount: otherBondAccount },
+        bondInitPayer
+      ),
+      ERROR.INVALID_MINT_AUTHORITY
+    )
+  })
+
+  it('#initBondSale()', async () => {
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const { programAuthority } = await bonds.getProgramAuthority()
+    const mintInfo = await tokenBond.getMintInfo()
+
+    assert.equal(bondSale.supplyMode, 1)
+    assert.ok(mintInfo.mintAuthority?.equals(programAuthority))
+    assert.ok(mintInfo.supply.eqn(0))
+  })
+
+  it('#createBond()', async () => {
+    const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+    await bonds.createBond(createBondVars, bondOwner)
+
+    assert.ok((await tokenBond.getMintInfo()).supply.eqn(0))
+  })
+
+  it('#claimBond() mints', async () => {
+    await sleep(2000)
+
+    const ownerBondAccount = await tokenBond.createAccount(bondOwner.publicKey)
+    const claimBondVars: ClaimBond = {
+      bondSale: bondSalePubkey,
+      ownerBondAccount,
+      bondId: new BN(0),
+      owner: bondOwner.publicKey
+    }
+    await bonds.claimBond(claimBondVars, bondOwner)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok((await tokenBond.getAccountInfo(ownerBondAccount)).amount.eqn(100))
+    assert.ok((await tokenBond.getMintInfo()).supply.eqn(100))
+    assert.ok(bondSale.mintedAmount.v.eqn(100))
+  })
+
+  it('#endBondSale() returns mint authority', async () => {
+    await bonds.endBondSale({ bondSale: bondSalePubkey, payerBondAccount }, bondInitPayer)
+
+    const mintInfo = await tokenBond.getMintInfo()
+    assert.ok(mintInfo.mintAuthority?.equals(bondInitPayer.publicKey))
+    assert.ok(mintInfo.supply.eqn(100))
+    assert.ok((await tokenBond.getAccountInfo(payerBondAccount)).amount.eqn(0))
+  })
+})
@@ -48,6 +48,7 @@ describe('modify-bond-sale', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await sale.createState(admin.publicKey, admin)
+    await sale.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
 
   describe('initBondPayer', () => {
@@ -61,6 +62,7 @@ describe('modify-bond-sale', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -133,6 +135,7 @@ describe('modify-bond-sale', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -0,0 +1,112 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { NATIVE_MINT, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('native-sol', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+  const issuer = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12),
+      connection.requestAirdrop(issuer.publicKey, 1e9)
+    ])
+
+    const token = await createToken(connection, wallet, mintAuthority)
+    tokenBond = new Token(connection, token.publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, NATIVE_MINT, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: NATIVE_MINT })
+  })
+
+  it('#initBondSale()', async () => {
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR.muln(1000),
+      payerBondAccount,
+      proceedsDestination: issuer.publicKey,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(20)
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.proceedsDestination.equals(issuer.publicKey))
+  })
+
+  it('#createBond() with lamports', async () => {
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const balanceBefore = await connection.getBalance(bondOwner.publicKey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      owner: bondOwner.publicKey
+    }
+    await bonds.createBond(createBondVars, bondOwner)
+
+    const bond = await bonds.getBondById(bondSalePubkey, new BN(0))
+    const balanceAfter = await connection.getBalance(bondOwner.publicKey)
+    const vault = await tokenQuote.getAccountInfo(bondSale.tokenQuoteAccount)
+
+    assert.ok(new BN(balanceBefore - balanceAfter).gt(bond.quoteAmount.v))
+    assert.ok(vault.amount.eq(bond.quoteAmount.v))
+  })
+
+  it('#claimQuote() in native SOL', async () => {
+    const { quoteAmount } = await bonds.getBondSale(bondSalePubkey)
+    const balanceBefore = await connection.getBalance(issuer.publicKey)
+
+    await bonds.claimQuote(
+      { bondSale: bondSalePubkey, payer: bondInitPayer.publicKey },
+      bondInitPayer
+    )
+
+    const balanceAfter = await connection.getBalance(issuer.publicKey)
+    assert.ok(new BN(balanceAfter - balanceBefore).eq(quoteAmount.v))
+    assert.ok((await bonds.getBondSale(bondSalePubkey)).quoteAmount.v.eqn(0))
+  })
+})
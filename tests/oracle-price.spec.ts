@@ -0,0 +1,223 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN, Program } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey, SystemProgram, Transaction } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  CreateBond,
+  InitBondSale,
+  PriceMode,
+  PRICE_FEED_SIZE
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, signAndSend, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('oracle-price', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  const mockOracle = anchor.workspace.MockOracle as Program
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+  const priceFeed = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  const setPrice = async (price: BN, confidence: BN, publishTime: BN | null = null) => {
+    await mockOracle.rpc.setPrice(price, confidence, publishTime, {
+      accounts: { priceFeed: priceFeed.publicKey }
+    })
+  }
+
+  const createBondVars = (): CreateBond => ({
+    amount: new BN(10),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    // the feed is a raw account owned by the mock oracle
+    const createFeedTx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: wallet.publicKey,
+        newAccountPubkey: priceFeed.publicKey,
+        space: PRICE_FEED_SIZE,
+        lamports: await connection.getMinimumBalanceForRentExemption(PRICE_FEED_SIZE),
+        programId: mockOracle.programId
+      })
+    )
+    await signAndSend(createFeedTx, connection, [wallet, priceFeed])
+    await setPrice(DENOMINATOR.muln(2), DENOMINATOR.divn(100))
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#changePriceMode() by other than payer', async () => {
+    await assertThrowsAsync(
+      bonds.changePriceMode(
+        {
+          bondSale: bondSalePubkey,
+          priceMode: PriceMode.Oracle,
+          oracle: priceFeed.publicKey,
+          payer: bondOwner.publicKey
+        },
+        bondOwner
+      ),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#changePriceMode() with a feed of another program', async () => {
+    await assertThrowsAsync(
+      bonds.changePriceMode(
+        {
+          bondSale: bondSalePubkey,
+          priceMode: PriceMode.Oracle,
+          oracle: priceFeed.publicKey,
+          oracleProgram: TOKEN_PROGRAM_ID,
+          discount: toDecimal(new BN(1), 1).v,
+          maxStaleness: new BN(60),
+          maxConfidence: toDecimal(new BN(1), 2).v,
+          payer: bondInitPayer.publicKey
+        },
+        bondInitPayer
+      ),
+      ERROR.INVALID_ORACLE
+    )
+  })
+
+  it('#changePriceMode() with a wide confidence', async () => {
+    await assertThrowsAsync(
+      bonds.changePriceMode(
+        {
+          bondSale: bondSalePubkey,
+          priceMode: PriceMode.Oracle,
+          oracle: priceFeed.publicKey,
+          oracleProgram: mockOracle.programId,
+          discount: toDecimal(new BN(1), 1).v,
+          maxStaleness: new BN(60),
+          maxConfidence: toDecimal(new BN(1), 3).v,
+          payer: bondInitPayer.publicKey
+        },
+        bondInitPayer
+      ),
+      ERROR.ORACLE_CONFIDENCE_EXCEEDED
+    )
+  })
+
+  it('#changePriceMode()', async () => {
+    await bonds.changePriceMode(
+      {
+        bondSale: bondSalePubkey,
+        priceMode: PriceMode.Oracle,
+        oracle: priceFeed.publicKey,
+        oracleProgram: mockOracle.programId,
+        discount: toDecimal(new BN(1), 1).v,
+        maxStaleness: new BN(60),
+        maxConfidence: toDecimal(new BN(1), 2).v,
+        payer: bondInitPayer.publicKey
+      },
+      bondInitPayer
+    )
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.equal(bondSale.priceMode, PriceMode.Oracle)
+    assert.ok(bondSale.oracle.equals(priceFeed.publicKey))
+    assert.ok(bondSale.oracleProgram.equals(mockOracle.programId))
+    assert.ok(bondSale.floorPrice.v.eq(toDecimal(new BN(18), 1).v))
+  })
+
+  it('#createBond() follows the feed', async () => {
+    await setPrice(DENOMINATOR.muln(3), DENOMINATOR.divn(100))
+
+    await bonds.createBond(createBondVars(), bondOwner)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.floorPrice.v.eq(toDecimal(new BN(27), 1).v))
+    assert.ok(bondSale.previousPrice.v.gt(bondSale.floorPrice.v))
+  })
+
+  it('#createBond() with a wide confidence', async () => {
+    await setPrice(DENOMINATOR.muln(3), DENOMINATOR.divn(10))
+
+    await assertThrowsAsync(
+      bonds.createBond(createBondVars(), bondOwner),
+      ERROR.ORACLE_CONFIDENCE_EXCEEDED
+    )
+  })
+
+  it('#createBond() with a stale feed', async () => {
+    const { lastTrade } = await bonds.getBondSale(bondSalePubkey)
+    await setPrice(DENOMINATOR.muln(3), DENOMINATOR.divn(100), lastTrade.subn(1000))
+
+    await assertThrowsAsync(bonds.createBond(createBondVars(), bondOwner), ERROR.STALE_ORACLE)
+  })
+
+  it('#changePriceMode() back to fixed', async () => {
+    await bonds.changePriceMode(
+      { bondSale: bondSalePubkey, priceMode: PriceMode.Fixed, payer: bondInitPayer.publicKey },
+      bondInitPayer
+    )
+    await bonds.createBond(createBondVars(), bondOwner)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.equal(bondSale.priceMode, PriceMode.Fixed)
+    assert.ok(bondSale.floorPrice.v.eq(toDecimal(new BN(27), 1).v))
+  })
+})
@@ -0,0 +1,119 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, sleep } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('poke-price', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  const createBondVars = (amount: number): CreateBond => ({
+    amount: new BN(amount),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR,
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#pokePrice() applies the decay', async () => {
+    await bonds.createBond(createBondVars(500), bondOwner)
+    const bondSaleBefore = await bonds.getBondSale(bondSalePubkey)
+    await sleep(2000)
+
+    // anyone can crank the price
+    await bonds.pokePrice({ bondSale: bondSalePubkey })
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.lastTrade.gt(bondSaleBefore.lastTrade))
+    assert.ok(bondSale.previousPrice.v.lt(bondSaleBefore.previousPrice.v))
+    assert.ok(bondSale.previousPrice.v.gt(DENOMINATOR))
+    assert.ok(bondSale.remainingAmount.v.eq(bondSaleBefore.remainingAmount.v))
+    assert.ok(bondSale.lastObservedPrice.v.eq(bondSale.previousPrice.v))
+  })
+
+  it('#pokePrice() keeps the price at the floor', async () => {
+    await sleep(2000)
+    const bondSaleBefore = await bonds.getBondSale(bondSalePubkey)
+    // fast enough to decay the remaining premium within the elapsed time
+    await bonds.changeVelocity(
+      {
+        bondSale: bondSalePubkey,
+        velocity: DENOMINATOR.muln(1000),
+        payer: bondInitPayer.publicKey
+      },
+      bondInitPayer
+    )
+    await bonds.pokePrice({ bondSale: bondSalePubkey })
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSaleBefore.previousPrice.v.gt(DENOMINATOR))
+    assert.ok(bondSale.previousPrice.v.eq(DENOMINATOR))
+  })
+})
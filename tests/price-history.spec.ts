@@ -0,0 +1,132 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { calculateAveragePrice } from '@invariant-labs/bonds-sdk/lib/math'
+import { DENOMINATOR, sleep } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('price-history', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  const createBondVars = (amount: number): CreateBond => ({
+    amount: new BN(amount),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR,
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#createPriceHistory()', async () => {
+    await bonds.createPriceHistory({ bondSale: bondSalePubkey })
+
+    const priceHistory = await bonds.getPriceHistory(bondSalePubkey)
+    assert.ok(priceHistory.bondSale.equals(bondSalePubkey))
+    assert.equal(priceHistory.count, 0)
+  })
+
+  it('#pokePrice() records observations', async () => {
+    await bonds.pokePrice({ bondSale: bondSalePubkey, withPriceHistory: true })
+    await sleep(2000)
+    await bonds.pokePrice({ bondSale: bondSalePubkey, withPriceHistory: true })
+
+    const priceHistory = await bonds.getPriceHistory(bondSalePubkey)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const latest = priceHistory.observations[priceHistory.head]
+    assert.equal(priceHistory.count, 2)
+    assert.equal(priceHistory.head, 1)
+    assert.ok(latest.timestamp.eq(bondSale.lastObservation))
+    assert.ok(latest.priceCumulative.eq(bondSale.priceCumulative))
+    // nothing was sold, the price stayed at the floor
+    assert.ok(calculateAveragePrice(priceHistory.observations[0], latest).eq(DENOMINATOR))
+  })
+
+  it('#pokePrice() without the price history', async () => {
+    await sleep(1000)
+    await bonds.pokePrice({ bondSale: bondSalePubkey })
+
+    const priceHistory = await bonds.getPriceHistory(bondSalePubkey)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.equal(priceHistory.count, 2)
+    assert.ok(bondSale.lastObservation.gt(priceHistory.observations[1].timestamp))
+  })
+
+  it('#createBond() accumulates the price', async () => {
+    const bondSaleBefore = await bonds.getBondSale(bondSalePubkey)
+    await sleep(1000)
+    await bonds.createBond(createBondVars(100), bondOwner)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const elapsed = bondSale.lastObservation.sub(bondSaleBefore.lastObservation)
+    assert.ok(elapsed.gtn(0))
+    const accumulated = bondSale.priceCumulative.sub(bondSaleBefore.priceCumulative)
+    assert.ok(accumulated.eq(DENOMINATOR.mul(elapsed)))
+    assert.ok(bondSale.lastObservedPrice.v.eq(bondSale.previousPrice.v))
+  })
+})
@@ -0,0 +1,192 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  ClaimQuote,
+  CreateBond,
+  EndBondSale,
+  InitBondSale
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('proceeds-splits', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+  const liquidity = Keypair.generate()
+  const partner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let payerBondAccount: PublicKey
+  let payerQuoteAccount: PublicKey
+  let liquidityQuoteAccount: PublicKey
+  let partnerQuoteAccount: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    payerQuoteAccount = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    liquidityQuoteAccount = await tokenQuote.createAccount(liquidity.publicKey)
+    partnerQuoteAccount = await tokenQuote.createAccount(partner.publicKey)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 2000)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+  })
+
+  it('#initBondSale() with splits above 100%', async () => {
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: payerQuoteAccount,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(20),
+      proceedsSplits: [
+        { recipient: liquidityQuoteAccount, bps: 6000 },
+        { recipient: partnerQuoteAccount, bps: 5000 }
+      ]
+    }
+
+    await assertThrowsAsync(
+      bonds.initBondSale(initBondSaleVars, bondInitPayer),
+      ERROR.INVALID_PROCEEDS_SPLITS
+    )
+  })
+
+  it('#initBondSale()', async () => {
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: payerQuoteAccount,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(20),
+      proceedsSplits: [
+        { recipient: liquidityQuoteAccount, bps: 5000 },
+        { recipient: partnerQuoteAccount, bps: 2500 }
+      ]
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.proceedsSplits[0].recipient.equals(liquidityQuoteAccount))
+    assert.equal(bondSale.proceedsSplits[0].bps, 5000)
+    assert.ok(bondSale.proceedsSplits[1].recipient.equals(partnerQuoteAccount))
+    assert.equal(bondSale.proceedsSplits[1].bps, 2500)
+    assert.equal(bondSale.proceedsSplits[2].bps, 0)
+  })
+
+  it('#createBond()', async () => {
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.createBond(createBondVars, bondOwner)
+  })
+
+  it('#claimQuote()', async () => {
+    const claimQuoteVars: ClaimQuote = {
+      bondSale: bondSalePubkey,
+      payer: bondInitPayer.publicKey
+    }
+    await bonds.claimQuote(claimQuoteVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.quoteAmount.v.eqn(0))
+    assert.ok(bondSale.proceedsSplits[0].distributed.v.eqn(50))
+    assert.ok(bondSale.proceedsSplits[1].distributed.v.eqn(25))
+    assert.ok((await tokenQuote.getAccountInfo(liquidityQuoteAccount)).amount.eqn(50))
+    assert.ok((await tokenQuote.getAccountInfo(partnerQuoteAccount)).amount.eqn(25))
+    assert.ok((await tokenQuote.getAccountInfo(payerQuoteAccount)).amount.eqn(26))
+  })
+
+  it('#createBond() again', async () => {
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.createBond(createBondVars, bondOwner)
+  })
+
+  it('#endBondSale()', async () => {
+    const { quoteRaised } = await bonds.getBondSale(bondSalePubkey)
+    const endBondSaleVars: EndBondSale = {
+      bondSale: bondSalePubkey,
+      payerBondAccount,
+      payer: bondInitPayer.publicKey
+    }
+    await bonds.endBondSale(endBondSaleVars, bondInitPayer)
+
+    const liquidityAmount = (await tokenQuote.getAccountInfo(liquidityQuoteAccount)).amount
+    const partnerAmount = (await tokenQuote.getAccountInfo(partnerQuoteAccount)).amount
+    const payerAmount = (await tokenQuote.getAccountInfo(payerQuoteAccount)).amount
+    assert.ok(liquidityAmount.eq(quoteRaised.v.muln(5000).divn(10000)))
+    assert.ok(partnerAmount.eq(quoteRaised.v.muln(2500).divn(10000)))
+    assert.ok(liquidityAmount.add(partnerAmount).add(payerAmount).eq(quoteRaised.v))
+    await assertThrowsAsync(bonds.getBondSale(bondSalePubkey), ERROR.ACCOUNT_NOT_EXISTS)
+  })
+})
@@ -0,0 +1,134 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { ClaimQuote, CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('proceeds-vesting', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let payerBondAccount: PublicKey
+  let payerQuoteAccount: PublicKey
+  let ownerQuoteAccount: PublicKey
+  let claimQuoteVars: ClaimQuote
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+  })
+
+  it('#initBondSale()', async () => {
+    payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    payerQuoteAccount = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(5),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: payerQuoteAccount,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(5),
+      proceedsVestingTime: new BN(10)
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+    claimQuoteVars = {
+      bondSale: bondSalePubkey,
+      payer: bondInitPayer.publicKey
+    }
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.proceedsVestingTime.eqn(10))
+  })
+
+  it('#createBond()', async () => {
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(500),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(2), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.createBond(createBondVars, bondOwner)
+  })
+
+  it('#claimQuote() during sale', async () => {
+    await bonds.claimQuote(claimQuoteVars, bondInitPayer)
+
+    const payerQuoteAccountInfo = await tokenQuote.getAccountInfo(payerQuoteAccount)
+    assert.ok(payerQuoteAccountInfo.amount.eqn(0))
+  })
+
+  it('#claimQuote() during proceeds vesting', async () => {
+    await sleep(10000)
+    await bonds.claimQuote(claimQuoteVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const payerQuoteAccountInfo = await tokenQuote.getAccountInfo(payerQuoteAccount)
+    assert.ok(payerQuoteAccountInfo.amount.gtn(0))
+    assert.ok(payerQuoteAccountInfo.amount.lt(bondSale.quoteRaised.v))
+    assert.ok(bondSale.quoteAmount.v.add(payerQuoteAccountInfo.amount).eq(bondSale.quoteRaised.v))
+  })
+
+  it('#claimQuote() after proceeds vesting', async () => {
+    await sleep(6000)
+    await bonds.claimQuote(claimQuoteVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const payerQuoteAccountInfo = await tokenQuote.getAccountInfo(payerQuoteAccount)
+    assert.ok(payerQuoteAccountInfo.amount.eq(bondSale.quoteRaised.v))
+    assert.ok(bondSale.quoteAmount.v.eqn(0))
+  })
+})
@@ -0,0 +1,191 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  ChangeReferralFee,
+  ClaimReferral,
+  CreateBond,
+  InitBondSale
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('referral', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+  const referrer = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let referrerQuoteAccount: PublicKey
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12),
+      connection.requestAirdrop(referrer.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+  })
+
+  it('#initBondSale()', async () => {
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(100),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(20)
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#changeReferralFee() above fee', async () => {
+    const changeReferralFeeVars: ChangeReferralFee = {
+      bondSale: bondSalePubkey,
+      admin: admin.publicKey,
+      referralFee: DENOMINATOR.addn(1)
+    }
+
+    await assertThrowsAsync(
+      bonds.changeReferralFee(changeReferralFeeVars, admin),
+      ERROR.INVALID_REFERRAL_FEE
+    )
+  })
+
+  it('#changeReferralFee()', async () => {
+    const changeReferralFeeVars: ChangeReferralFee = {
+      bondSale: bondSalePubkey,
+      admin: admin.publicKey,
+      referralFee: DENOMINATOR.divn(2)
+    }
+    await bonds.changeReferralFee(changeReferralFeeVars, admin)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.referralFee.v.eq(DENOMINATOR.divn(2)))
+  })
+
+  it('#createReferral()', async () => {
+    referrerQuoteAccount = await tokenQuote.createAccount(referrer.publicKey)
+
+    await bonds.createReferral(
+      { bondSale: bondSalePubkey, referrerQuoteAccount, payer: referrer.publicKey },
+      referrer
+    )
+
+    const referral = await bonds.getReferral(bondSalePubkey, referrerQuoteAccount)
+    assert.ok(referral.bondSale.equals(bondSalePubkey))
+    assert.ok(referral.referrerQuoteAccount.equals(referrerQuoteAccount))
+    assert.ok(referral.referrer.equals(referrer.publicKey))
+    assert.ok(referral.amount.v.eqn(0))
+  })
+
+  it('#createBond() with own referral', async () => {
+    const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+    await bonds.createReferral(
+      {
+        bondSale: bondSalePubkey,
+        referrerQuoteAccount: ownerQuoteAccount,
+        payer: bondOwner.publicKey
+      },
+      bondOwner
+    )
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey,
+      referrerQuoteAccount: ownerQuoteAccount
+    }
+    await assertThrowsAsync(bonds.createBond(createBondVars, bondOwner), ERROR.SELF_REFERRAL)
+  })
+
+  it('#createBond() with referrer', async () => {
+    const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey,
+      referrerQuoteAccount
+    }
+    const bondPub = await bonds.createBond(createBondVars, bondOwner)
+
+    const bond = await bonds.getBondByAddress(bondPub)
+    const bondSaleAfter = await bonds.getBondSale(bondSalePubkey)
+    const referral = await bonds.getReferral(bondSalePubkey, referrerQuoteAccount)
+    assert.ok(bond.feeAmount.v.eqn(2))
+    assert.ok(bond.referralAmount.v.eqn(1))
+    assert.ok(bondSaleAfter.feeAmount.v.eqn(0))
+    assert.ok(bondSaleAfter.referralAmount.v.eqn(1))
+    assert.ok(referral.amount.v.eqn(1))
+    assert.ok((await bonds.getState()).totalFee.v.eqn(1))
+  })
+
+  it('#claimReferral()', async () => {
+    const claimReferralVars: ClaimReferral = {
+      bondSale: bondSalePubkey,
+      referrerQuoteAccount,
+      referrer: referrer.publicKey
+    }
+    await bonds.claimReferral(claimReferralVars, referrer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const referral = await bonds.getReferral(bondSalePubkey, referrerQuoteAccount)
+    assert.ok(bondSale.referralAmount.v.eqn(0))
+    assert.ok(referral.amount.v.eqn(0))
+    assert.ok((await tokenQuote.getAccountInfo(referrerQuoteAccount)).amount.eqn(1))
+  })
+})
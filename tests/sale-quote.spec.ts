@@ -0,0 +1,226 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  AddSaleQuote,
+  CreateBondWithSaleQuote,
+  InitBondSale
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('sale-quote', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+  const partner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let tokenOther: Token
+  let bondSalePubkey: PublicKey
+  let payerBondAccount: PublicKey
+  let otherDestination: PublicKey
+  let partnerOtherAccount: PublicKey
+  let ownerOtherAccount: PublicKey
+  let addSaleQuoteVars: AddSaleQuote
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12),
+      connection.requestAirdrop(partner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenOther = new Token(connection, tokens[2].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+    await bonds.createFeeVault({ tokenQuote: tokenOther.publicKey })
+
+    payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(10),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1),
+      proceedsSplits: [{ recipient: await tokenQuote.createAccount(partner.publicKey), bps: 2500 }]
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    // sale quote proceeds go to the associated token accounts of the same owners
+    otherDestination = await tokenOther.createAssociatedTokenAccount(bondInitPayer.publicKey)
+    partnerOtherAccount = await tokenOther.createAssociatedTokenAccount(partner.publicKey)
+    ownerOtherAccount = await tokenOther.createAccount(bondOwner.publicKey)
+    await tokenOther.mintTo(ownerOtherAccount, mintAuthority, [mintAuthority], 1000)
+
+    // one other token is worth two quote tokens
+    addSaleQuoteVars = {
+      bondSale: bondSalePubkey,
+      tokenQuote: tokenOther.publicKey,
+      proceedsDestination: otherDestination,
+      rate: DENOMINATOR.muln(2),
+      payer: bondInitPayer.publicKey
+    }
+  })
+
+  it('#addSaleQuote() with the main quote token', async () => {
+    const destination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+
+    await assertThrowsAsync(
+      bonds.addSaleQuote(
+        {
+          ...addSaleQuoteVars,
+          tokenQuote: tokenQuote.publicKey,
+          proceedsDestination: destination
+        },
+        bondInitPayer
+      ),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#addSaleQuote() to a non-associated account', async () => {
+    const destination = await tokenOther.createAccount(bondInitPayer.publicKey)
+
+    await assertThrowsAsync(
+      bonds.addSaleQuote({ ...addSaleQuoteVars, proceedsDestination: destination }, bondInitPayer),
+      ERROR.INVALID_QUOTE_ACCOUNT
+    )
+  })
+
+  it('#addSaleQuote() with zero rate', async () => {
+    await assertThrowsAsync(
+      bonds.addSaleQuote({ ...addSaleQuoteVars, rate: new BN(0) }, bondInitPayer),
+      ERROR.INVALID_RATE
+    )
+  })
+
+  it('#addSaleQuote()', async () => {
+    await bonds.addSaleQuote(addSaleQuoteVars, bondInitPayer)
+
+    const saleQuote = await bonds.getSaleQuote(bondSalePubkey, tokenOther.publicKey)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    assert.ok(saleQuote.bondSale.equals(bondSalePubkey))
+    assert.ok(saleQuote.proceedsDestination.equals(otherDestination))
+    assert.ok(saleQuote.rate.v.eq(DENOMINATOR.muln(2)))
+    assert.equal(bondSale.saleQuoteCount, 1)
+  })
+
+  it('#createBondWithSaleQuote()', async () => {
+    const bondSaleBefore = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBondWithSaleQuote = {
+      bondSale: bondSalePubkey,
+      tokenQuote: tokenOther.publicKey,
+      ownerQuoteAccount: ownerOtherAccount,
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSaleBefore.previousPrice, toDecimal(new BN(1), 1)),
+      owner: bondOwner.publicKey
+    }
+    const bondPub = await bonds.createBondWithSaleQuote(createBondVars, bondOwner)
+
+    const bond = await bonds.getBondByAddress(bondPub)
+    const saleQuote = await bonds.getSaleQuote(bondSalePubkey, tokenOther.publicKey)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    const { saleQuoteAddress } = await bonds.getSaleQuoteAddress(
+      bondSalePubkey,
+      tokenOther.publicKey
+    )
+    const spent = new BN(1000).sub((await tokenOther.getAccountInfo(ownerOtherAccount)).amount)
+
+    // about 100 quote tokens are paid with half as many other tokens
+    assert.ok(bond.saleQuote.equals(saleQuoteAddress))
+    assert.ok(bond.bondAmount.v.eqn(100))
+    assert.ok(spent.gtn(50) && spent.ltn(100))
+    assert.ok(bond.quoteAmount.v.eq(spent))
+    assert.ok(saleQuote.quoteRaised.v.add(bond.feeAmount.v).eq(spent))
+    assert.ok(bondSale.saleQuoteRaised.v.eq(saleQuote.quoteRaised.v.muln(2)))
+    assert.ok(bondSale.quoteRaised.v.eqn(0))
+  })
+
+  it('#claimQuote() claims sale quotes', async () => {
+    const saleQuote = await bonds.getSaleQuote(bondSalePubkey, tokenOther.publicKey)
+
+    await bonds.claimQuote({ bondSale: bondSalePubkey }, bondInitPayer)
+
+    // the partner split takes a quarter of the sale quote too
+    const partnerAmount = saleQuote.quoteRaised.v.muln(2500).divn(10000)
+    const destinationAmount = (await tokenOther.getAccountInfo(otherDestination)).amount
+    const saleQuoteAfter = await bonds.getSaleQuote(bondSalePubkey, tokenOther.publicKey)
+    assert.ok((await tokenOther.getAccountInfo(partnerOtherAccount)).amount.eq(partnerAmount))
+    assert.ok(destinationAmount.eq(saleQuote.quoteRaised.v.sub(partnerAmount)))
+    assert.ok(saleQuoteAfter.splitsDistributed[0].v.eq(partnerAmount))
+    assert.ok(saleQuoteAfter.quoteAmount.v.eqn(0))
+  })
+
+  it('#claimSaleQuote() closes after the sale ends', async () => {
+    await sleep(10000)
+
+    // tokens sent to the vault directly do not keep it open
+    const saleQuote = await bonds.getSaleQuote(bondSalePubkey, tokenOther.publicKey)
+    const destinationBefore = (await tokenOther.getAccountInfo(otherDestination)).amount
+    await tokenOther.mintTo(saleQuote.tokenQuoteAccount, mintAuthority, [mintAuthority], 5)
+
+    await bonds.claimSaleQuote(
+      { bondSale: bondSalePubkey, tokenQuote: tokenOther.publicKey },
+      bondInitPayer
+    )
+
+    const { saleQuoteAddress } = await bonds.getSaleQuoteAddress(
+      bondSalePubkey,
+      tokenOther.publicKey
+    )
+    const destinationAfter = (await tokenOther.getAccountInfo(otherDestination)).amount
+    assert.equal(await connection.getAccountInfo(saleQuoteAddress), null)
+    assert.equal(await connection.getAccountInfo(saleQuote.tokenQuoteAccount), null)
+    assert.ok(destinationAfter.sub(destinationBefore).eqn(5))
+    assert.equal((await bonds.getBondSale(bondSalePubkey)).saleQuoteCount, 0)
+  })
+
+  it('#endBondSale()', async () => {
+    await bonds.endBondSale({ bondSale: bondSalePubkey, payerBondAccount }, bondInitPayer)
+
+    assert.equal(await connection.getAccountInfo(bondSalePubkey), null)
+  })
+})
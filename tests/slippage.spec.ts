@@ -3,11 +3,16 @@ import { Provider, BN } from '@project-serum/anchor'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { Keypair, PublicKey } from '@solana/web3.js'
 import { Network } from '@invariant-labs/bonds-sdk'
-import { CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { CreateBond, CreateBondExactIn, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
 import { DENOMINATOR, toDecimal, ERROR } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
 import { assertThrowsAsync, createToken } from './testUtils'
 import { Bonds } from '@invariant-labs/bonds-sdk/src'
-import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/src/math'
+import {
+  calculateBuyAmount,
+  getAmountAfterSlippage,
+  getPriceAfterSlippage
+} from '@invariant-labs/bonds-sdk/src/math'
 
 describe('slippage', () => {
   const provider = Provider.local()
@@ -50,6 +55,7 @@ describe('slippage', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
 
   describe('limit above price', () => {
@@ -63,6 +69,7 @@ describe('slippage', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -102,6 +109,7 @@ describe('slippage', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -140,6 +148,7 @@ describe('slippage', () => {
         duration: new BN(100),
         floorPrice: DENOMINATOR,
         payerBondAccount,
+        proceedsDestination: payerQuoteAccount,
         tokenBond,
         tokenQuote,
         upBound: DENOMINATOR.divn(2),
@@ -170,4 +179,112 @@ describe('slippage', () => {
       )
     })
   })
+
+  describe('expired', () => {
+    it('#initBondSale()', async () => {
+      const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+      await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+      const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+      const initBondSaleVars: InitBondSale = {
+        supply: new BN(1000),
+        duration: new BN(100),
+        floorPrice: DENOMINATOR,
+        payerBondAccount,
+        proceedsDestination,
+        tokenBond,
+        tokenQuote,
+        upBound: DENOMINATOR.divn(2),
+        velocity: DENOMINATOR.divn(2),
+        payer: bondInitPayer.publicKey,
+        vestingTime: new BN(10)
+      }
+
+      bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+    })
+
+    it('#createBond()', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+      const createBondVars: CreateBond = {
+        amount: new BN(100),
+        priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(5), 2)),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey,
+        expiresAt: new BN(Math.floor(Date.now() / 1000) - 60)
+      }
+
+      await assertThrowsAsync(
+        bonds.createBond(createBondVars, bondOwner),
+        ERROR.TRANSACTION_EXPIRED
+      )
+    })
+  })
+
+  describe('exact in', () => {
+    it('#initBondSale()', async () => {
+      const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+      await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+      const proceedsDestination = await tokenQuote.createAccount(bondInitPayer.publicKey)
+      const initBondSaleVars: InitBondSale = {
+        supply: new BN(1000),
+        duration: new BN(100),
+        floorPrice: DENOMINATOR,
+        payerBondAccount,
+        proceedsDestination,
+        tokenBond,
+        tokenQuote,
+        upBound: DENOMINATOR.divn(2),
+        velocity: DENOMINATOR.divn(2),
+        payer: bondInitPayer.publicKey,
+        vestingTime: new BN(10)
+      }
+
+      bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+    })
+
+    it('#createBondExactIn() below minimum', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+
+      const createBondExactInVars: CreateBondExactIn = {
+        quoteAmount: new BN(100),
+        minBondOut: new BN(100),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey
+      }
+
+      await assertThrowsAsync(
+        bonds.createBondExactIn(createBondExactInVars, bondOwner),
+        ERROR.BOND_OUT_BELOW_MINIMUM
+      )
+    })
+
+    it('#createBondExactIn()', async () => {
+      const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+      await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+      const bondSale = await bonds.getBondSale(bondSalePubkey)
+      const expectedAmount = calculateBuyAmount(bondSale, new BN(100))
+
+      const createBondExactInVars: CreateBondExactIn = {
+        quoteAmount: new BN(100),
+        minBondOut: getAmountAfterSlippage(expectedAmount, toDecimal(new BN(5), 2)),
+        bondSale: bondSalePubkey,
+        ownerQuoteAccount,
+        owner: bondOwner.publicKey,
+        expiresAt: new BN(Math.floor(Date.now() / 1000) + 60)
+      }
+
+      const bondPub = await bonds.createBondExactIn(createBondExactInVars, bondOwner)
+      const bond = await bonds.getBondByAddress(bondPub)
+
+      assert.ok(bond.bondAmount.v.gte(createBondExactInVars.minBondOut))
+      assert.ok((await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount.gten(900))
+    })
+  })
 })
@@ -0,0 +1,238 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { NATIVE_MINT, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import {
+  ClaimBond,
+  ClaimQuote,
+  CreateBond,
+  EndBondSale,
+  InitBondSale,
+  RefundBond
+} from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR, sleep, toDecimal } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+import { getPriceAfterSlippage } from '@invariant-labs/bonds-sdk/lib/math'
+
+describe('soft-cap', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let payerBondAccount: PublicKey
+  let payerQuoteAccount: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+    await bonds.createFeeVault({ tokenQuote: NATIVE_MINT })
+  })
+
+  it('#initBondSale()', async () => {
+    payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    payerQuoteAccount = await tokenQuote.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(10),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: payerQuoteAccount,
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(5),
+      softCap: new BN(500)
+    }
+
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#createBond()', async () => {
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: bondSalePubkey,
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.createBond(createBondVars, bondOwner)
+
+    // fees stay in the sale until the soft cap is reached
+    const bondSaleAfter = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSaleAfter.feeAmount.v.eqn(2))
+    assert.ok((await bonds.getState()).totalFee.v.eqn(0))
+  })
+
+  it('#claimQuote() before soft cap', async () => {
+    const claimQuoteVars: ClaimQuote = {
+      bondSale: bondSalePubkey,
+      payer: bondInitPayer.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.claimQuote(claimQuoteVars, bondInitPayer),
+      ERROR.SOFT_CAP_NOT_REACHED
+    )
+  })
+
+  it('#refundBond() during sale', async () => {
+    const refundBondVars: RefundBond = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.refundBond(refundBondVars, bondOwner),
+      ERROR.REFUND_NOT_AVAILABLE
+    )
+  })
+
+  it('#claimBond() after failed sale', async () => {
+    await sleep(11000)
+
+    const ownerBondAccount = await tokenBond.createAccount(bondOwner.publicKey)
+    const claimBondVars: ClaimBond = {
+      bondSale: bondSalePubkey,
+      ownerBondAccount,
+      bondId: new BN(0),
+      owner: bondOwner.publicKey
+    }
+
+    await assertThrowsAsync(
+      bonds.claimBond(claimBondVars, bondOwner),
+      ERROR.SOFT_CAP_NOT_REACHED
+    )
+  })
+
+  it('#endBondSale() reclaims supply', async () => {
+    const endBondSaleVars: EndBondSale = {
+      bondSale: bondSalePubkey,
+      payerBondAccount,
+      payer: bondInitPayer.publicKey
+    }
+
+    await bonds.endBondSale(endBondSaleVars, bondInitPayer)
+
+    assert.ok((await tokenBond.getAccountInfo(payerBondAccount)).amount.eqn(1000))
+    assert.ok((await tokenQuote.getAccountInfo(payerQuoteAccount)).amount.eqn(0))
+    assert.ok((await bonds.getBondSale(bondSalePubkey)).remainingAmount.v.eqn(0))
+  })
+
+  it('#refundBond()', async () => {
+    const refundBondVars: RefundBond = {
+      bondSale: bondSalePubkey,
+      bondId: new BN(0),
+      ownerQuoteAccount,
+      owner: bondOwner.publicKey
+    }
+
+    await bonds.refundBond(refundBondVars, bondOwner)
+
+    assert.ok((await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount.eqn(1000))
+    assert.isUndefined(await bonds.getBondById(bondSalePubkey, new BN(0)))
+  })
+
+  it('#endBondSale() closes after refunds', async () => {
+    const endBondSaleVars: EndBondSale = {
+      bondSale: bondSalePubkey,
+      payerBondAccount,
+      payer: bondInitPayer.publicKey
+    }
+
+    await bonds.endBondSale(endBondSaleVars, bondInitPayer)
+    await assertThrowsAsync(bonds.getBondSale(bondSalePubkey), ERROR.ACCOUNT_NOT_EXISTS)
+  })
+
+  it('#refundBond() in native SOL', async () => {
+    const tokenNative = new Token(connection, NATIVE_MINT, TOKEN_PROGRAM_ID, wallet)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(10),
+      floorPrice: DENOMINATOR.muln(1000),
+      payerBondAccount,
+      proceedsDestination: bondInitPayer.publicKey,
+      tokenBond,
+      tokenQuote: tokenNative,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR.divn(2),
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(5),
+      softCap: new BN(1e9)
+    }
+    const nativeSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+    const bondSale = await bonds.getBondSale(nativeSalePubkey)
+
+    const createBondVars: CreateBond = {
+      amount: new BN(100),
+      priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+      bondSale: nativeSalePubkey,
+      owner: bondOwner.publicKey
+    }
+    await bonds.createBond(createBondVars, bondOwner)
+    await sleep(11000)
+
+    const balanceBefore = await connection.getBalance(bondOwner.publicKey)
+    const refundBondVars: RefundBond = {
+      bondSale: nativeSalePubkey,
+      bondId: new BN(0),
+      owner: bondOwner.publicKey
+    }
+    await bonds.refundBond(refundBondVars, bondOwner)
+
+    // the refund and the rent of the closed bond outweigh the transaction fee
+    const balanceAfter = await connection.getBalance(bondOwner.publicKey)
+    assert.ok(balanceAfter > balanceBefore)
+    assert.ok((await tokenNative.getAccountInfo(bondSale.tokenQuoteAccount)).amount.eqn(0))
+    assert.isUndefined(await bonds.getBondById(nativeSalePubkey, new BN(0)))
+  })
+})
@@ -0,0 +1,136 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, InitBondSale, SetVelocityController } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('velocity-controller', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+  let setVelocityControllerVars: SetVelocityController
+
+  const createBondVars = (amount: number): CreateBond => ({
+    amount: new BN(amount),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR,
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+
+    setVelocityControllerVars = {
+      bondSale: bondSalePubkey,
+      gain: DENOMINATOR,
+      minVelocity: DENOMINATOR.divn(2),
+      maxVelocity: DENOMINATOR.muln(2),
+      payer: bondInitPayer.publicKey
+    }
+  })
+
+  it('#setVelocityController() with inverted bounds', async () => {
+    await assertThrowsAsync(
+      bonds.setVelocityController(
+        {
+          ...setVelocityControllerVars,
+          minVelocity: DENOMINATOR.muln(3)
+        },
+        bondInitPayer
+      ),
+      ERROR.INVALID_VELOCITY_BOUNDS
+    )
+  })
+
+  it('#setVelocityController() by other than payer', async () => {
+    await assertThrowsAsync(
+      bonds.setVelocityController(
+        { ...setVelocityControllerVars, payer: bondOwner.publicKey },
+        bondOwner
+      ),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#setVelocityController()', async () => {
+    await bonds.setVelocityController(setVelocityControllerVars, bondInitPayer)
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.velocityGain.v.eq(DENOMINATOR))
+    assert.ok(bondSale.minVelocity.v.eq(DENOMINATOR.divn(2)))
+    assert.ok(bondSale.maxVelocity.v.eq(DENOMINATOR.muln(2)))
+    assert.ok(bondSale.velocity.v.eq(DENOMINATOR))
+  })
+
+  it('#createBond() ahead of schedule slows the decay down', async () => {
+    await bonds.createBond(createBondVars(500), bondOwner)
+    await bonds.createBond(createBondVars(10), bondOwner)
+
+    // half of the supply is sold right after the start
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.velocity.v.lt(DENOMINATOR.muln(6).divn(10)))
+    assert.ok(bondSale.velocity.v.gte(DENOMINATOR.divn(2)))
+  })
+})
@@ -0,0 +1,168 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('vesting-terms', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  const DAY = 86400
+
+  const createBondVars = (amount: number, vestingTerm?: number): CreateBond => ({
+    amount: new BN(amount),
+    vestingTerm,
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR,
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#setVestingTerms() with unsorted terms', async () => {
+    await assertThrowsAsync(
+      bonds.setVestingTerms(
+        {
+          bondSale: bondSalePubkey,
+          vestingTerms: [
+            { vestingTime: new BN(30 * DAY), discount: DENOMINATOR.divn(50) },
+            { vestingTime: new BN(7 * DAY), discount: DENOMINATOR.divn(100) }
+          ],
+          payer: bondInitPayer.publicKey
+        },
+        bondInitPayer
+      ),
+      ERROR.INVALID_VESTING_TERMS
+    )
+  })
+
+  it('#setVestingTerms() by other than payer', async () => {
+    await assertThrowsAsync(
+      bonds.setVestingTerms(
+        {
+          bondSale: bondSalePubkey,
+          vestingTerms: [{ vestingTime: new BN(7 * DAY), discount: DENOMINATOR.divn(100) }],
+          payer: bondOwner.publicKey
+        },
+        bondOwner
+      ),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#setVestingTerms()', async () => {
+    await bonds.setVestingTerms(
+      {
+        bondSale: bondSalePubkey,
+        vestingTerms: [
+          { vestingTime: new BN(7 * DAY), discount: DENOMINATOR.divn(100) },
+          { vestingTime: new BN(30 * DAY), discount: DENOMINATOR.divn(50) },
+          { vestingTime: new BN(90 * DAY), discount: DENOMINATOR.divn(20) }
+        ],
+        payer: bondInitPayer.publicKey
+      },
+      bondInitPayer
+    )
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.vestingTerms[2].vestingTime.eqn(90 * DAY))
+    assert.ok(bondSale.vestingTerms[2].discount.v.eq(DENOMINATOR.divn(20)))
+    assert.ok(bondSale.vestingTerms[3].vestingTime.eqn(0))
+  })
+
+  it('#createBond() with a term that is not offered', async () => {
+    await assertThrowsAsync(
+      bonds.createBond(createBondVars(10, 3), bondOwner),
+      ERROR.INVALID_VESTING_TERM
+    )
+  })
+
+  it('#createBond() with the longest term', async () => {
+    const balanceBefore = (await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount
+
+    // 400 tokens at 1.1 with 5% off
+    const bondPubkey = await bonds.createBond(createBondVars(400, 2), bondOwner)
+
+    const bond = await bonds.getBondByAddress(bondPubkey)
+    const balanceAfter = (await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount
+    assert.ok(balanceBefore.sub(balanceAfter).eqn(418))
+    assert.ok(bond.vestingTime.eqn(90 * DAY))
+    assert.ok(bond.vestingDiscount.v.eq(DENOMINATOR.divn(20)))
+    assert.ok(bond.vestingEnd.sub(bond.vestingStart).eqn(90 * DAY))
+  })
+
+  it('#createBond() with the sale vesting time', async () => {
+    const bondPubkey = await bonds.createBond(createBondVars(10), bondOwner)
+
+    const bond = await bonds.getBondByAddress(bondPubkey)
+    assert.ok(bond.vestingTime.eqn(1000))
+    assert.ok(bond.vestingDiscount.v.eqn(0))
+    assert.ok(bond.vestingEnd.sub(bond.vestingStart).eqn(1000))
+  })
+})
@@ -0,0 +1,158 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { Network } from '@invariant-labs/bonds-sdk'
+import { CreateBond, InitBondSale } from '@invariant-labs/bonds-sdk/lib/sale'
+import { DENOMINATOR, ERROR } from '@invariant-labs/bonds-sdk/lib/utils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken } from './testUtils'
+import { Bonds } from '@invariant-labs/bonds-sdk/src'
+
+describe('volume-tiers', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondInitPayer = Keypair.generate()
+  const bondOwner = Keypair.generate()
+
+  let bonds: Bonds
+  let tokenBond: Token
+  let tokenQuote: Token
+  let bondSalePubkey: PublicKey
+  let ownerQuoteAccount: PublicKey
+
+  const createBondVars = (amount: number): CreateBond => ({
+    amount: new BN(amount),
+    priceLimit: DENOMINATOR.muln(10),
+    bondSale: bondSalePubkey,
+    ownerQuoteAccount,
+    owner: bondOwner.publicKey
+  })
+
+  before(async () => {
+    bonds = await Bonds.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Bonds.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e12),
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(wallet.publicKey, 1e12),
+      connection.requestAirdrop(bondInitPayer.publicKey, 1e12),
+      connection.requestAirdrop(bondOwner.publicKey, 1e12)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    tokenBond = new Token(connection, tokens[0].publicKey, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
+
+    await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
+
+    const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+    ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
+    await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 10000)
+
+    const initBondSaleVars: InitBondSale = {
+      supply: new BN(1000),
+      duration: new BN(1000),
+      floorPrice: DENOMINATOR,
+      payerBondAccount,
+      proceedsDestination: await tokenQuote.createAccount(bondInitPayer.publicKey),
+      tokenBond,
+      tokenQuote,
+      upBound: DENOMINATOR.divn(2),
+      velocity: DENOMINATOR,
+      payer: bondInitPayer.publicKey,
+      vestingTime: new BN(1000)
+    }
+    bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+  })
+
+  it('#setVolumeTiers() with unsorted tiers', async () => {
+    await assertThrowsAsync(
+      bonds.setVolumeTiers(
+        {
+          bondSale: bondSalePubkey,
+          volumeTiers: [
+            { minAmount: new BN(400), discount: DENOMINATOR.divn(5) },
+            { minAmount: new BN(100), discount: DENOMINATOR.divn(10) }
+          ],
+          payer: bondInitPayer.publicKey
+        },
+        bondInitPayer
+      ),
+      ERROR.INVALID_VOLUME_TIERS
+    )
+  })
+
+  it('#setVolumeTiers() with a full discount', async () => {
+    await assertThrowsAsync(
+      bonds.setVolumeTiers(
+        {
+          bondSale: bondSalePubkey,
+          volumeTiers: [{ minAmount: new BN(100), discount: DENOMINATOR }],
+          payer: bondInitPayer.publicKey
+        },
+        bondInitPayer
+      ),
+      ERROR.INVALID_VOLUME_TIERS
+    )
+  })
+
+  it('#setVolumeTiers() by other than payer', async () => {
+    await assertThrowsAsync(
+      bonds.setVolumeTiers(
+        {
+          bondSale: bondSalePubkey,
+          volumeTiers: [{ minAmount: new BN(100), discount: DENOMINATOR.divn(10) }],
+          payer: bondOwner.publicKey
+        },
+        bondOwner
+      ),
+      ERROR.CONSTRAINT_RAW
+    )
+  })
+
+  it('#setVolumeTiers()', async () => {
+    await bonds.setVolumeTiers(
+      {
+        bondSale: bondSalePubkey,
+        volumeTiers: [
+          { minAmount: new BN(100), discount: DENOMINATOR.divn(10) },
+          { minAmount: new BN(400), discount: DENOMINATOR.divn(5) }
+        ],
+        payer: bondInitPayer.publicKey
+      },
+      bondInitPayer
+    )
+
+    const bondSale = await bonds.getBondSale(bondSalePubkey)
+    assert.ok(bondSale.volumeTiers[0].minAmount.v.eqn(100))
+    assert.ok(bondSale.volumeTiers[1].discount.v.eq(DENOMINATOR.divn(5)))
+    assert.ok(bondSale.volumeTiers[2].minAmount.v.eqn(0))
+  })
+
+  it('#createBond() with a volume discount', async () => {
+    const balanceBefore = (await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount
+
+    // 400 tokens at 1.1 with 20% off
+    await bonds.createBond(createBondVars(400), bondOwner)
+
+    const balanceAfter = (await tokenQuote.getAccountInfo(ownerQuoteAccount)).amount
+    assert.ok(balanceBefore.sub(balanceAfter).eqn(352))
+  })
+})
@@ -24,7 +24,7 @@ describe('withdraw-fee', () => {
   let bonds: Bonds
   let tokenBond: Token
   let tokenQuote: Token
-  let bondSalePubkey: PublicKey
+  const bondSalePubkeys: PublicKey[] = []
   let payerQuoteAccount: PublicKey
   let adminQuoteAccount: PublicKey
 
@@ -53,57 +53,77 @@ describe('withdraw-fee', () => {
     tokenQuote = new Token(connection, tokens[1].publicKey, TOKEN_PROGRAM_ID, wallet)
 
     await bonds.createState(admin.publicKey, admin)
+    await bonds.createFeeVault({ tokenQuote: tokenQuote.publicKey })
   })
   describe('withdraw-fee', () => {
     it('#initBondSale()', async () => {
-      const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
       payerQuoteAccount = await tokenQuote.createAccount(bondInitPayer.publicKey)
-      await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
-
-      const initBondSaleVars: InitBondSale = {
-        supply: new BN(1000),
-        duration: new BN(100),
-        floorPrice: DENOMINATOR,
-        payerBondAccount,
-        tokenBond,
-        tokenQuote,
-        upBound: DENOMINATOR.divn(2),
-        velocity: DENOMINATOR.divn(2),
-        payer: bondInitPayer.publicKey,
-        vestingTime: new BN(20)
-      }
 
-      bondSalePubkey = await bonds.initBondSale(initBondSaleVars, bondInitPayer)
+      for (let i = 0; i < 2; i++) {
+        const payerBondAccount = await tokenBond.createAccount(bondInitPayer.publicKey)
+        await tokenBond.mintTo(payerBondAccount, mintAuthority, [mintAuthority], 1000)
+
+        const initBondSaleVars: InitBondSale = {
+          supply: new BN(1000),
+          duration: new BN(100),
+          floorPrice: DENOMINATOR,
+          payerBondAccount,
+          proceedsDestination: payerQuoteAccount,
+          tokenBond,
+          tokenQuote,
+          upBound: DENOMINATOR.divn(2),
+          velocity: DENOMINATOR.divn(2),
+          payer: bondInitPayer.publicKey,
+          vestingTime: new BN(20)
+        }
+
+        bondSalePubkeys.push(await bonds.initBondSale(initBondSaleVars, bondInitPayer))
+      }
     })
 
     it('#createBond()', async () => {
       const ownerQuoteAccount = await tokenQuote.createAccount(bondOwner.publicKey)
       await tokenQuote.mintTo(ownerQuoteAccount, mintAuthority, [mintAuthority], 1000)
-      const bondSale = await bonds.getBondSale(bondSalePubkey)
-
-      const createBondVars: CreateBond = {
-        amount: new BN(100),
-        priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
-        bondSale: bondSalePubkey,
-        ownerQuoteAccount,
-        owner: bondOwner.publicKey
+
+      for (const bondSalePubkey of bondSalePubkeys) {
+        const bondSale = await bonds.getBondSale(bondSalePubkey)
+
+        const createBondVars: CreateBond = {
+          amount: new BN(100),
+          priceLimit: getPriceAfterSlippage(bondSale.previousPrice, toDecimal(new BN(1), 1)),
+          bondSale: bondSalePubkey,
+          ownerQuoteAccount,
+          owner: bondOwner.publicKey
+        }
+
+        await bonds.createBond(createBondVars, bondOwner)
+
+        const bondSaleAfter = await bonds.getBondSale(bondSalePubkey)
+        assert.ok(bondSaleAfter.feeAmount.v.eqn(0))
       }
 
-      await bonds.createBond(createBondVars, bondOwner)
+      const feeVault = await bonds.getFeeVault(tokenQuote.publicKey)
+      assert.ok((await bonds.getState()).totalFee.v.eqn(4))
+      assert.ok((await tokenQuote.getAccountInfo(feeVault.tokenAccount)).amount.eqn(4))
     })
 
     it('#withdrawFee()', async () => {
       adminQuoteAccount = await tokenQuote.createAccount(admin.publicKey)
 
       const withdrawFeeVars: WithdrawFee = {
-        bondSale: bondSalePubkey,
+        tokenQuote: tokenQuote.publicKey,
         adminQuoteAccount,
         admin: admin.publicKey
       }
 
       await bonds.withdrawFee(withdrawFeeVars, admin)
 
-      assert.ok((await tokenQuote.getAccountInfo(adminQuoteAccount)).amount.eqn(2))
+      const state = await bonds.getState()
+      const feeVault = await bonds.getFeeVault(tokenQuote.publicKey)
+      assert.ok(state.totalFee.v.eqn(4))
+      assert.ok(state.totalWithdrawn.v.eqn(4))
+      assert.ok((await tokenQuote.getAccountInfo(adminQuoteAccount)).amount.eqn(4))
+      assert.ok((await tokenQuote.getAccountInfo(feeVault.tokenAccount)).amount.eqn(0))
     })
   })
 })